@@ -1,3 +1,5 @@
+use std::fs;
+use std::path::Path;
 use std::process::Command;
 
 fn main() {
@@ -25,4 +27,20 @@ fn main() {
 
     // Re-run if git HEAD changes
     println!("cargo:rerun-if-changed=../../.git/HEAD");
+    // ...or when a commit moves the branch HEAD points to, stored either as a
+    // loose ref or in packed-refs
+    let git_dir = Path::new("../../.git");
+    if let Ok(head) = fs::read_to_string(git_dir.join("HEAD"))
+        && let Some(branch) = head.strip_prefix("ref: ")
+    {
+        rerun_if_exists(&git_dir.join(branch.trim()));
+    }
+    rerun_if_exists(&git_dir.join("packed-refs"));
+}
+
+/// Watch `path`; cargo would re-run the script on every build for a missing one.
+fn rerun_if_exists(path: &Path) {
+    if path.exists() {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
 }
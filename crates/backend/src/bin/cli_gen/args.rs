@@ -0,0 +1,303 @@
+use chrono::Datelike;
+use clap::Args;
+use cli_codegen_backend::models::config::{
+    Architecture, CONFIG_SCHEMA_VERSION, CiTarget, CliConfig, ErrorHandling, GlobalOption, Layout,
+    LicenseType, Logging, SwCliDependency,
+};
+
+/// Arguments of `cli_gen generate`
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Name of the CLI application
+    #[clap(short = 'n', long = "name", default_value = "my-cli")]
+    name: String,
+
+    /// Short description of the CLI
+    #[clap(short = 's', long = "short-desc")]
+    short_description: Option<String>,
+
+    /// Long description of the CLI
+    #[clap(long = "long-desc")]
+    long_description: Option<String>,
+
+    /// Author name
+    #[clap(short = 'a', long = "author")]
+    author: Option<String>,
+
+    /// Copyright information
+    #[clap(long = "copyright")]
+    copyright: Option<String>,
+
+    /// License type (MIT, Apache2, GPL3)
+    #[clap(long = "license", default_value = "MIT")]
+    license: String,
+
+    /// Repository URL
+    #[clap(short = 'r', long = "repository")]
+    repository: Option<String>,
+
+    /// Version
+    #[clap(long = "version-string", default_value = "0.1.0")]
+    version: String,
+
+    /// sw-cli repository URL
+    #[clap(
+        long = "sw-cli-url",
+        default_value = "https://github.com/softwarewrighter/sw-cli.git"
+    )]
+    sw_cli_url: String,
+
+    /// How to depend on sw-cli (submodule, git, path, registry, vendored, standalone)
+    #[clap(long = "sw-cli-mode", default_value = "submodule")]
+    sw_cli_mode: String,
+
+    /// Git revision to pin sw-cli to (git mode)
+    #[clap(long = "sw-cli-rev")]
+    sw_cli_rev: Option<String>,
+
+    /// Git tag to pin sw-cli to (git mode)
+    #[clap(long = "sw-cli-tag")]
+    sw_cli_tag: Option<String>,
+
+    /// Path to an sw-cli checkout (path and vendored modes)
+    #[clap(long = "sw-cli-path")]
+    sw_cli_path: Option<String>,
+
+    /// sw-cli version requirement (registry mode)
+    #[clap(long = "sw-cli-version")]
+    sw_cli_version: Option<String>,
+
+    /// Code architecture (simple, dispatch)
+    #[clap(long = "architecture", default_value = "simple")]
+    architecture: String,
+
+    /// File layout (single-file, split, commands-dir, workspace)
+    #[clap(long = "layout", default_value = "split")]
+    layout: String,
+
+    /// Emit snapshot tests of the --help output of every command
+    #[clap(long = "snapshot-tests", action)]
+    snapshot_tests: bool,
+
+    /// Add a `completions <shell>` subcommand to the generated CLI
+    #[clap(long = "completions", action)]
+    completions: bool,
+
+    /// Add man page generation (`cargo run --example mangen`)
+    #[clap(long = "man-pages", action)]
+    man_pages: bool,
+
+    /// Emit a `cargo xtask` crate (setup, build, dist, bump-version) instead of shell scripts
+    #[clap(long = "xtask", action)]
+    xtask: bool,
+
+    /// Global options of the generated parser, comma separated
+    /// (verbose, dry-run, quiet, verbosity, color, config, output, no-input)
+    #[clap(
+        long = "global-options",
+        value_delimiter = ',',
+        default_value = "verbose,dry-run"
+    )]
+    global_options: Vec<String>,
+
+    /// Logging scaffold (none, tracing, env-logger)
+    #[clap(long = "logging", default_value = "none")]
+    logging: String,
+
+    /// Emit logs as JSON (tracing only)
+    #[clap(long = "log-json", action)]
+    log_json: bool,
+
+    /// Add a --log-file option to the generated CLI
+    #[clap(long = "log-file", action)]
+    log_file: bool,
+
+    /// Error handling scaffold (none, thiserror, anyhow)
+    #[clap(long = "errors", default_value = "none")]
+    errors: String,
+
+    /// Follow the sysexits(3) exit-code conventions
+    #[clap(long = "sysexits", action)]
+    sysexits: bool,
+
+    /// Add a layered TOML settings loader and a `config` subcommand
+    #[clap(long = "settings", action)]
+    settings: bool,
+
+    /// CI configurations to emit, comma separated (github, gitlab, makefile)
+    #[clap(long = "ci", value_delimiter = ',')]
+    ci: Vec<String>,
+
+    /// Output directory for generated code
+    #[clap(short = 'o', long = "output", default_value = "./generated_cli")]
+    pub output_dir: String,
+
+    /// Include version support
+    #[clap(long = "version-support", action)]
+    version_support: bool,
+
+    /// Include help support
+    #[clap(long = "help-support", action)]
+    help_support: bool,
+
+    /// Show the files that would be written without writing anything
+    #[clap(long = "dry-run", action)]
+    pub dry_run: bool,
+
+    /// With --dry-run, also print a unified diff against files already on disk
+    #[clap(long = "diff", action, requires = "dry_run")]
+    pub diff: bool,
+}
+
+impl GenerateArgs {
+    /// Create a configuration from the command line arguments
+    pub fn config(&self) -> Result<CliConfig, String> {
+        Ok(CliConfig {
+            schema_version: CONFIG_SCHEMA_VERSION,
+            name: self.name.clone(),
+            short_description: self
+                .short_description
+                .clone()
+                .unwrap_or_else(|| "A brief description of the CLI".to_string()),
+            long_description: self
+                .long_description
+                .clone()
+                .unwrap_or_else(|| "A longer description of what this CLI does".to_string()),
+            author: self
+                .author
+                .clone()
+                .unwrap_or_else(|| "Your Name".to_string()),
+            copyright: self.copyright.clone().unwrap_or_else(|| {
+                format!("Copyright (c) {}", chrono::Utc::now().date_naive().year())
+            }),
+            license: self.license(),
+            repository: self
+                .repository
+                .clone()
+                .unwrap_or_else(|| format!("https://github.com/yourusername/{}", self.name)),
+            version: self.version.clone(),
+            sw_cli_url: self.sw_cli_url.clone(),
+            sw_cli_dependency: self.sw_cli_dependency()?,
+            version_support: self.version_support,
+            help_support: self.help_support,
+            architecture: self.architecture()?,
+            layout: self.layout()?,
+            snapshot_tests: self.snapshot_tests,
+            completions: self.completions,
+            man_pages: self.man_pages,
+            xtask: self.xtask,
+            ci: self.ci()?,
+            logging: self.logging()?,
+            log_json: self.log_json,
+            log_file: self.log_file,
+            error_handling: self.error_handling()?,
+            sysexits: self.sysexits,
+            settings: self.settings,
+            global_options: self.global_options()?,
+            ..CliConfig::default()
+        })
+    }
+
+    fn license(&self) -> LicenseType {
+        match self.license.as_str() {
+            "MIT" => LicenseType::MIT,
+            "Apache2" => LicenseType::Apache2,
+            "GPL3" => LicenseType::GPL3,
+            other => LicenseType::Custom(other.to_string()),
+        }
+    }
+
+    fn sw_cli_dependency(&self) -> Result<SwCliDependency, String> {
+        let required =
+            |value: &Option<String>, message: &str| value.clone().ok_or(message.to_string());
+        Ok(match self.sw_cli_mode.as_str() {
+            "submodule" => SwCliDependency::Submodule,
+            "git" => SwCliDependency::Git {
+                rev: self.sw_cli_rev.clone(),
+                tag: self.sw_cli_tag.clone(),
+            },
+            "path" => SwCliDependency::Path {
+                path: required(&self.sw_cli_path, "--sw-cli-path is required in path mode")?,
+            },
+            "registry" => SwCliDependency::Registry {
+                version: required(
+                    &self.sw_cli_version,
+                    "--sw-cli-version is required in registry mode",
+                )?,
+            },
+            "vendored" => SwCliDependency::Vendored {
+                source: required(
+                    &self.sw_cli_path,
+                    "--sw-cli-path is required in vendored mode",
+                )?,
+            },
+            "standalone" => SwCliDependency::Standalone,
+            other => return Err(format!("Unknown sw-cli mode: {}", other)),
+        })
+    }
+
+    fn architecture(&self) -> Result<Architecture, String> {
+        match self.architecture.as_str() {
+            "simple" => Ok(Architecture::Simple),
+            "dispatch" => Ok(Architecture::Dispatch),
+            other => Err(format!("Unknown architecture: {}", other)),
+        }
+    }
+
+    fn layout(&self) -> Result<Layout, String> {
+        match self.layout.as_str() {
+            "single-file" => Ok(Layout::SingleFile),
+            "split" => Ok(Layout::Split),
+            "commands-dir" => Ok(Layout::CommandsDir),
+            "workspace" => Ok(Layout::Workspace),
+            other => Err(format!("Unknown layout: {}", other)),
+        }
+    }
+
+    fn ci(&self) -> Result<Vec<CiTarget>, String> {
+        self.ci
+            .iter()
+            .map(|target| match target.as_str() {
+                "github" => Ok(CiTarget::GitHubActions),
+                "gitlab" => Ok(CiTarget::GitLab),
+                "makefile" => Ok(CiTarget::Makefile),
+                other => Err(format!("Unknown CI target: {}", other)),
+            })
+            .collect()
+    }
+
+    fn logging(&self) -> Result<Logging, String> {
+        match self.logging.as_str() {
+            "none" => Ok(Logging::None),
+            "tracing" => Ok(Logging::Tracing),
+            "env-logger" => Ok(Logging::EnvLogger),
+            other => Err(format!("Unknown logging backend: {}", other)),
+        }
+    }
+
+    fn error_handling(&self) -> Result<ErrorHandling, String> {
+        match self.errors.as_str() {
+            "none" => Ok(ErrorHandling::None),
+            "thiserror" => Ok(ErrorHandling::Thiserror),
+            "anyhow" => Ok(ErrorHandling::Anyhow),
+            other => Err(format!("Unknown error handling: {}", other)),
+        }
+    }
+
+    fn global_options(&self) -> Result<Vec<GlobalOption>, String> {
+        self.global_options
+            .iter()
+            .map(|option| match option.as_str() {
+                "verbose" => Ok(GlobalOption::Verbose),
+                "dry-run" => Ok(GlobalOption::DryRun),
+                "quiet" => Ok(GlobalOption::Quiet),
+                "verbosity" => Ok(GlobalOption::Verbosity),
+                "color" => Ok(GlobalOption::Color),
+                "config" => Ok(GlobalOption::ConfigFile),
+                "output" => Ok(GlobalOption::Output),
+                "no-input" => Ok(GlobalOption::NoInput),
+                other => Err(format!("Unknown global option: {}", other)),
+            })
+            .collect()
+    }
+}
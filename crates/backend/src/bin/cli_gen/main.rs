@@ -0,0 +1,274 @@
+mod args;
+
+use args::GenerateArgs;
+use clap::Parser;
+use cli_codegen_backend::{
+    codegen::generators::CodeGenerator,
+    import::ClapImporter,
+    migrate::ConfigMigrator,
+    models::config::{CONFIG_SCHEMA_VERSION, CliConfig},
+    schema::{ConfigSchema, Severity},
+};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+// CLI Command definitions
+#[derive(Parser)]
+#[clap(
+    version = "1.0",
+    about = "CLI Code Generator - Generate standardized CLI applications"
+)]
+enum CliCommands {
+    /// Generate a new CLI project
+    #[clap(name = "generate")]
+    Generate(Box<GenerateArgs>),
+
+    /// Serve the web UI
+    #[clap(name = "serve")]
+    Serve {
+        /// Port to run the server on
+        #[clap(short = 'p', long = "port", default_value = "3000")]
+        port: u16,
+    },
+
+    /// Process configurations from a batch file
+    #[clap(name = "batch")]
+    Batch {
+        /// Path to the configuration file
+        #[clap(short = 'f', long = "file")]
+        config_file: String,
+
+        /// Output directory for generated code
+        #[clap(short = 'o', long = "output", default_value = "./generated_cli")]
+        output_dir: String,
+
+        /// Show the files that would be written without writing anything
+        #[clap(long = "dry-run", action)]
+        dry_run: bool,
+
+        /// With --dry-run, also print a unified diff against files already on disk
+        #[clap(long = "diff", action, requires = "dry_run")]
+        diff: bool,
+    },
+
+    /// Create a batch configuration from an existing clap-based crate
+    #[clap(name = "import")]
+    Import {
+        /// Path to the crate to import
+        path: String,
+
+        /// Write the configuration to this file instead of stdout
+        #[clap(short = 'o', long = "output")]
+        output_file: Option<String>,
+    },
+
+    /// Re-render a generated project with the current templates, merging local edits
+    #[clap(name = "update")]
+    Update {
+        /// Directory of the previously generated project
+        #[clap(short = 'o', long = "output", default_value = "./generated_cli")]
+        output_dir: String,
+
+        /// Use the configuration from this JSON file instead of the recorded one
+        #[clap(short = 'f', long = "file")]
+        config_file: Option<String>,
+
+        /// Report what would change without writing anything
+        #[clap(long = "dry-run", action)]
+        dry_run: bool,
+    },
+
+    /// Re-record the help snapshots of a generated project
+    RefreshSnapshots {
+        /// Directory of the generated project
+        #[clap(short = 'o', long = "output", default_value = "./generated_cli")]
+        output_dir: String,
+    },
+
+    /// Print the JSON Schema of configuration files
+    #[clap(name = "schema")]
+    Schema {
+        /// Write the schema to this file instead of stdout
+        #[clap(short = 'o', long = "output")]
+        output_file: Option<String>,
+    },
+
+    /// Check a configuration file, reporting every problem with its position
+    #[clap(name = "validate")]
+    Validate {
+        /// Path to the JSON configuration file
+        file: String,
+    },
+
+    /// Upgrade configuration files written by older versions in place
+    #[clap(name = "migrate")]
+    Migrate {
+        /// Paths to the JSON configuration files
+        #[clap(required = true)]
+        files: Vec<String>,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    match CliCommands::parse() {
+        CliCommands::Generate(args) => generate(&args),
+        CliCommands::Serve { port: _port } => {
+            // This command should be handled by the main server binary
+            eprintln!("Serve command should be run via the main server binary");
+            eprintln!("Run: cargo run -p cli-codegen-backend --bin cli-codegen-backend");
+            Ok(())
+        }
+        CliCommands::Batch {
+            config_file,
+            output_dir,
+            dry_run,
+            diff,
+        } => batch(&config_file, &output_dir, dry_run, diff),
+        CliCommands::Import { path, output_file } => import(&path, output_file),
+        CliCommands::Update {
+            output_dir,
+            config_file,
+            dry_run,
+        } => update(&output_dir, config_file, dry_run),
+        CliCommands::RefreshSnapshots { output_dir } => refresh_snapshots(&output_dir),
+        CliCommands::Schema { output_file } => schema(output_file),
+        CliCommands::Validate { file } => validate(&file),
+        CliCommands::Migrate { files } => migrate(&files),
+    }
+}
+
+fn generate(args: &GenerateArgs) -> Result<()> {
+    let config = args.config()?;
+    CodeGenerator::validate_config(&config)?;
+    if args.dry_run {
+        return print_plan(&config, &args.output_dir, args.diff);
+    }
+
+    // Generate the CLI code
+    CodeGenerator::generate_files(&config, &args.output_dir)?;
+    println!("CLI code generated successfully to: {}", args.output_dir);
+
+    Ok(())
+}
+
+fn batch(config_file: &str, output_dir: &str, dry_run: bool, diff: bool) -> Result<()> {
+    // Read configuration from file
+    let config_content = std::fs::read_to_string(config_file)?;
+    let config = ConfigMigrator::from_json(&config_content)?;
+
+    // Validate the configuration
+    CodeGenerator::validate_config(&config)?;
+
+    if dry_run {
+        return print_plan(&config, output_dir, diff);
+    }
+
+    // Generate the CLI code
+    CodeGenerator::generate_files(&config, output_dir)?;
+    println!(
+        "CLI code generated successfully from batch config to: {}",
+        output_dir
+    );
+
+    Ok(())
+}
+
+fn print_plan(config: &CliConfig, output_dir: &str, diff: bool) -> Result<()> {
+    let plan = CodeGenerator::plan_files(config, output_dir)?;
+    CodeGenerator::print_plan(&plan, diff);
+    Ok(())
+}
+
+fn import(path: &str, output_file: Option<String>) -> Result<()> {
+    let config = ClapImporter::import(path)?;
+    CodeGenerator::validate_config(&config)?;
+
+    let json = serde_json::to_string_pretty(&config)?;
+    match output_file {
+        Some(output_file) => {
+            std::fs::write(&output_file, json + "\n")?;
+            println!("Configuration imported from {} to: {}", path, output_file);
+        }
+        None => println!("{}", json),
+    }
+
+    Ok(())
+}
+
+fn update(output_dir: &str, config_file: Option<String>, dry_run: bool) -> Result<()> {
+    let config = match config_file {
+        Some(config_file) => {
+            let config_content = std::fs::read_to_string(config_file)?;
+            Some(ConfigMigrator::from_json(&config_content)?)
+        }
+        None => None,
+    };
+
+    let report = CodeGenerator::update_project(output_dir, config, dry_run)?;
+    CodeGenerator::print_update_report(&report);
+
+    let conflicts = report.conflicts();
+    if conflicts > 0 && !dry_run {
+        return Err(format!("{} file(s) have merge conflicts", conflicts).into());
+    }
+
+    Ok(())
+}
+
+fn refresh_snapshots(output_dir: &str) -> Result<()> {
+    let snapshots = CodeGenerator::refresh_snapshots(output_dir)?;
+    for snapshot in &snapshots {
+        println!("Recorded {}", snapshot);
+    }
+    println!(
+        "Refreshed {} help snapshot(s) in {}",
+        snapshots.len(),
+        output_dir
+    );
+    Ok(())
+}
+
+fn schema(output_file: Option<String>) -> Result<()> {
+    let json = serde_json::to_string_pretty(&ConfigSchema::schema())?;
+    match output_file {
+        Some(output_file) => {
+            std::fs::write(&output_file, json + "\n")?;
+            println!("Schema written to: {}", output_file);
+        }
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
+fn validate(file: &str) -> Result<()> {
+    let source = std::fs::read_to_string(file)?;
+    let diagnostics = ConfigSchema::validate(&source);
+    for diagnostic in &diagnostics {
+        eprintln!("{}:{}", file, diagnostic);
+    }
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .count();
+    if errors == 0 {
+        println!("{} is valid", file);
+        return Ok(());
+    }
+    Err(format!("{} problem(s) found in {}", errors, file).into())
+}
+
+fn migrate(files: &[String]) -> Result<()> {
+    for file in files {
+        let version = ConfigMigrator::migrate_file(file)?;
+        if version == CONFIG_SCHEMA_VERSION {
+            println!("{} is already at schema version {}", file, version);
+        } else {
+            println!(
+                "Migrated {} from schema version {} to {}",
+                file, version, CONFIG_SCHEMA_VERSION
+            );
+        }
+    }
+    Ok(())
+}
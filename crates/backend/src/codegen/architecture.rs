@@ -0,0 +1,210 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{Architecture, ArgConfig, ArgKind, CliConfig};
+
+/// How a builder field for an argument is stored and defaulted.
+enum BuilderField {
+    /// Same type as the config field, with an empty default
+    Plain { ty: String, default: &'static str },
+    /// Wrapped in `Option` and required by `build()`
+    Required { ty: String },
+}
+
+impl CodeGenerator {
+    /// Fields of `CliConfigBuilder` for the global options and the
+    /// configured top-level arguments.
+    pub(crate) fn render_builder_fields(config: &CliConfig) -> String {
+        let mut lines: Vec<String> = Self::builder_entries(config)
+            .into_iter()
+            .map(|(name, field)| match field {
+                BuilderField::Plain { ty, .. } => format!("    {}: {},", name, ty),
+                BuilderField::Required { ty } => format!("    {}: Option<{}>,", name, ty),
+            })
+            .collect();
+        if !config.commands.is_empty() {
+            lines.push("    command: Option<Commands>,".to_string());
+        }
+        lines.join("\n")
+    }
+
+    pub(crate) fn render_builder_defaults(config: &CliConfig) -> String {
+        let mut lines: Vec<String> = Self::builder_entries(config)
+            .into_iter()
+            .map(|(name, field)| match field {
+                BuilderField::Plain { default, .. } => {
+                    format!("            {}: {},", name, default)
+                }
+                BuilderField::Required { .. } => format!("            {}: None,", name),
+            })
+            .collect();
+        if !config.commands.is_empty() {
+            lines.push("            command: None,".to_string());
+        }
+        lines.join("\n")
+    }
+
+    pub(crate) fn render_builder_setters(config: &CliConfig) -> String {
+        let mut out = String::new();
+        for (name, field) in Self::builder_entries(config) {
+            let (param_ty, value) = match field {
+                BuilderField::Plain { ty, .. } if ty.starts_with("Option<") => {
+                    let inner = ty["Option<".len()..ty.len() - 1].to_string();
+                    (inner, format!("Some({})", name))
+                }
+                BuilderField::Plain { ty, .. } => (ty, name.clone()),
+                BuilderField::Required { ty } => (ty, format!("Some({})", name)),
+            };
+            out.push_str(&format!(
+                "\n    pub fn {name}(mut self, {name}: {ty}) -> Self {{\n        self.{name} = {value};\n        self\n    }}\n",
+                name = name,
+                ty = param_ty,
+                value = value
+            ));
+        }
+        if !config.commands.is_empty() {
+            out.push_str(
+                "\n    pub fn command(mut self, command: Commands) -> Self {\n        self.command = Some(command);\n        self\n    }\n",
+            );
+        }
+        out.trim_end().to_string()
+    }
+
+    pub(crate) fn render_builder_build(config: &CliConfig) -> String {
+        let mut lines: Vec<String> = Self::builder_entries(config)
+            .into_iter()
+            .map(|(name, field)| match field {
+                BuilderField::Plain { .. } => {
+                    format!("            {name}: self.{name},", name = name)
+                }
+                BuilderField::Required { .. } => format!(
+                    "            {name}: self.{name}.ok_or(\"missing value for `{name}`\")?,",
+                    name = name
+                ),
+            })
+            .collect();
+        if !config.commands.is_empty() {
+            lines.push("            command: self.command,".to_string());
+        }
+        lines.join("\n")
+    }
+
+    /// Builder fields of the global options followed by those of the
+    /// top-level arguments, by field name.
+    fn builder_entries(config: &CliConfig) -> Vec<(String, BuilderField)> {
+        let mut entries: Vec<(String, BuilderField)> = Self::global_fields(config)
+            .into_iter()
+            .map(|field| {
+                (
+                    field.name.to_string(),
+                    BuilderField::Plain {
+                        ty: field.ty.to_string(),
+                        default: field.default,
+                    },
+                )
+            })
+            .collect();
+        entries.extend(
+            config
+                .args
+                .iter()
+                .map(|arg| (arg.name.clone(), Self::builder_field(arg))),
+        );
+        entries
+    }
+
+    fn builder_field(arg: &ArgConfig) -> BuilderField {
+        let ty = Self::field_type(None, arg);
+        let default = match arg.kind {
+            ArgKind::Flag => "false",
+            ArgKind::Count => "0",
+            _ if arg.multiple => "Vec::new()",
+            _ if ty.starts_with("Option<") => "None",
+            // Values clap fills from a default or requires on the command line
+            _ => return BuilderField::Required { ty },
+        };
+        BuilderField::Plain { ty, default }
+    }
+
+    /// Match arms of `CliDispatcher::dispatch`, one per configured command.
+    pub(crate) fn render_dispatch_arms(config: &CliConfig) -> String {
+        config
+            .commands
+            .iter()
+            .map(|command| {
+                format!(
+                    "            Some(Commands::{}(command)) => command.handle(config),",
+                    Self::variant_name(&command.name)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// A `CommandHandler` implementation for each command's arguments struct.
+    pub(crate) fn render_handlers(config: &CliConfig) -> String {
+        let mut out = String::new();
+        for command in &config.commands {
+            if Self::is_completions_command(config, command) {
+                out.push_str(&Self::render_completions_handler(config));
+                continue;
+            }
+            if Self::is_settings_command(config, command) {
+                out.push_str(&Self::render_settings_handler(config));
+                continue;
+            }
+            let trace = Self::render_verbose_trace(config, "config", &command.name);
+            out.push_str(&format!(
+                r#"
+impl CommandHandler for crate::config::{variant}Command {{
+    fn handle(&self, {param}: &CliConfig) -> {result} {{
+{trace}        // TODO: Implement the {name} command
+        println!("{name} is not implemented yet");
+        Ok(())
+    }}
+}}
+"#,
+                variant = Self::variant_name(&command.name),
+                param = Self::handler_param(config, "config"),
+                result = Self::result_type(config),
+                trace = if trace.is_empty() {
+                    trace
+                } else {
+                    trace + "\n"
+                },
+                name = command.name
+            ));
+        }
+        out.trim_end().to_string()
+    }
+
+    pub(crate) fn validate_architecture(config: &CliConfig) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if config.architecture != Architecture::Dispatch {
+            return errors;
+        }
+
+        if config.commands.is_empty() {
+            errors.push(ValidationError::new(
+                "/architecture",
+                "The dispatch architecture needs at least one command",
+            ));
+        }
+        // Builder methods that would clash with argument setters
+        for (i, arg) in config
+            .args
+            .iter()
+            .enumerate()
+            .filter(|(_, arg)| arg.name == "new" || arg.name == "build")
+        {
+            errors.push(ValidationError::new(
+                format!("/args/{}/name", i),
+                format!(
+                    "Argument name '{}' is reserved by the dispatch architecture",
+                    arg.name
+                ),
+            ));
+        }
+
+        errors
+    }
+}
@@ -0,0 +1,74 @@
+use crate::codegen::generators::{CodeGenerator, FILE_MODE};
+use crate::models::config::{CiTarget, CliConfig, SwCliDependency};
+
+impl CodeGenerator {
+    /// CI configurations of the selected targets, as (template name, output
+    /// path, mode).
+    pub(crate) fn ci_outputs(config: &CliConfig) -> Vec<(&'static str, String, u32)> {
+        config
+            .ci
+            .iter()
+            .map(|target| match target {
+                CiTarget::GitHubActions => (
+                    "github_ci.yml",
+                    ".github/workflows/ci.yml".to_string(),
+                    FILE_MODE,
+                ),
+                CiTarget::GitLab => ("gitlab_ci.yml", ".gitlab-ci.yml".to_string(), FILE_MODE),
+                CiTarget::Makefile => ("Makefile", "Makefile".to_string(), FILE_MODE),
+            })
+            .collect()
+    }
+
+    /// Command building the release artifacts in CI.
+    pub(crate) fn render_release_command(config: &CliConfig) -> String {
+        if config.xtask {
+            "cargo xtask dist".to_string()
+        } else {
+            format!("cargo build --release --package {}", config.name)
+        }
+    }
+
+    /// Path of the release artifacts built by [`CodeGenerator::render_release_command`].
+    pub(crate) fn render_release_artifacts(config: &CliConfig) -> String {
+        if config.xtask {
+            "target/dist".to_string()
+        } else {
+            format!("target/release/{}", config.name)
+        }
+    }
+
+    /// `with:` block of `actions/checkout` fetching the sw-cli submodule.
+    pub(crate) fn render_github_checkout(config: &CliConfig) -> String {
+        if config.sw_cli_dependency == SwCliDependency::Submodule {
+            "        with:\n          submodules: recursive".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    pub(crate) fn render_gitlab_variables(config: &CliConfig) -> String {
+        if config.sw_cli_dependency == SwCliDependency::Submodule {
+            "  GIT_SUBMODULE_STRATEGY: recursive".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// `submodules` target of the `Makefile`, a prerequisite of the others.
+    pub(crate) fn render_make_submodules(config: &CliConfig) -> String {
+        if config.sw_cli_dependency == SwCliDependency::Submodule {
+            "\nsubmodules:\n\tgit submodule update --init --recursive".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    pub(crate) fn render_make_prerequisites(config: &CliConfig) -> String {
+        if config.sw_cli_dependency == SwCliDependency::Submodule {
+            " submodules".to_string()
+        } else {
+            String::new()
+        }
+    }
+}
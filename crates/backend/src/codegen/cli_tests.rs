@@ -0,0 +1,180 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::models::config::{ArgConfig, ArgGroupConfig, ArgKind, CliConfig, CommandConfig};
+
+/// Longest method chain rustfmt keeps on a single line.
+const CHAIN_WIDTH: usize = 60;
+
+impl CodeGenerator {
+    /// `#[test]` functions of the generated `tests/cli.rs`.
+    pub(crate) fn render_cli_tests(config: &CliConfig) -> String {
+        let top_level_required = Self::needs_arguments(&config.args, &config.groups);
+        let usage_code = format!(".code({})", Self::usage_exit_code(config));
+
+        let mut tests = Self::render_top_level_tests(config, &usage_code);
+        if top_level_required {
+            tests.push(Self::render_missing_arguments_test(
+                "missing_required_arguments_fails",
+                None,
+                &usage_code,
+            ));
+        } else {
+            tests.push(Self::render_test(
+                "runs_without_arguments",
+                &["cli()", ".assert()", ".success()"],
+            ));
+        }
+
+        for command in &config.commands {
+            tests.extend(Self::render_command_tests(
+                command,
+                top_level_required,
+                &usage_code,
+            ));
+        }
+        tests.concat().trim_end().to_string()
+    }
+
+    /// Tests of the CLI itself: help, version and an unknown flag.
+    fn render_top_level_tests(config: &CliConfig, usage_code: &str) -> Vec<String> {
+        vec![
+            Self::render_test(
+                "help_shows_usage",
+                &[
+                    "cli()",
+                    ".arg(\"--help\")",
+                    ".assert()",
+                    ".success()",
+                    &Self::contains("stdout", &format!("Usage: {}", config.name)),
+                ],
+            ),
+            Self::render_test(
+                "version_shows_package_version",
+                &[
+                    "cli()",
+                    ".arg(\"--version\")",
+                    ".assert()",
+                    ".success()",
+                    ".stdout(predicate::str::contains(env!(\"CARGO_PKG_VERSION\")))",
+                ],
+            ),
+            Self::render_test(
+                "unknown_flag_is_a_usage_error",
+                &[
+                    "cli()",
+                    ".arg(\"--no-such-flag\")",
+                    ".assert()",
+                    ".failure()",
+                    usage_code,
+                ],
+            ),
+        ]
+    }
+
+    /// Tests of one subcommand: its help, and running it with or without
+    /// arguments.
+    fn render_command_tests(
+        command: &CommandConfig,
+        top_level_required: bool,
+        usage_code: &str,
+    ) -> Vec<String> {
+        let prefix = command.name.replace('-', "_");
+        // clap shows the first line of the description, minus a trailing period
+        let summary = command
+            .description
+            .lines()
+            .next()
+            .map(|line| line.trim().trim_end_matches('.'))
+            .filter(|line| !line.is_empty())
+            .unwrap_or("Usage:");
+        let mut tests = vec![Self::render_test(
+            &format!("{}_help_describes_command", prefix),
+            &[
+                "cli()",
+                &format!(".args([\"{}\", \"--help\"])", command.name),
+                ".assert()",
+                ".success()",
+                &Self::contains("stdout", summary),
+            ],
+        )];
+
+        if Self::needs_arguments(&command.args, &command.groups) {
+            tests.push(Self::render_missing_arguments_test(
+                &format!("{}_without_required_arguments_fails", prefix),
+                Some(&command.name),
+                usage_code,
+            ));
+        } else if !top_level_required {
+            tests.push(Self::render_test(
+                &format!("{}_runs", prefix),
+                &[
+                    "cli()",
+                    &format!(".arg(\"{}\")", command.name),
+                    ".assert()",
+                    ".success()",
+                ],
+            ));
+        }
+
+        tests
+    }
+
+    /// A test expecting a usage error when `subcommand` (or the CLI itself)
+    /// is run without its required arguments.
+    fn render_missing_arguments_test(
+        name: &str,
+        subcommand: Option<&str>,
+        usage_code: &str,
+    ) -> String {
+        let arg = subcommand.map(|subcommand| format!(".arg(\"{}\")", subcommand));
+        let stderr = Self::contains("stderr", "required arguments were not provided");
+        let mut chain = vec!["cli()"];
+        chain.extend(arg.as_deref());
+        chain.extend([".assert()", ".failure()", usage_code, &stderr]);
+        Self::render_test(name, &chain)
+    }
+
+    /// `.stdout(predicate::str::contains("..."))` or the `stderr` equivalent,
+    /// with the text on its own line once the call gets too wide for rustfmt.
+    fn contains(stream: &str, text: &str) -> String {
+        let call = format!("predicate::str::contains(\"{}\")", Self::escape(text));
+        if call.len() <= CHAIN_WIDTH {
+            format!(".{}({})", stream, call)
+        } else {
+            format!(
+                ".{}(predicate::str::contains(\n            \"{}\",\n        ))",
+                stream,
+                Self::escape(text)
+            )
+        }
+    }
+
+    /// A test running one method chain, laid out the way rustfmt would.
+    fn render_test(name: &str, chain: &[&str]) -> String {
+        let one_line = chain.concat();
+        let body = if one_line.len() <= CHAIN_WIDTH {
+            format!("    {};\n", one_line)
+        } else {
+            let (first, rest) = chain.split_first().unwrap_or((&"", &[]));
+            let mut body = format!("    {}\n", first);
+            for call in rest {
+                body.push_str(&format!("        {}\n", call));
+            }
+            body.trim_end().to_string() + ";\n"
+        };
+        format!("\n#[test]\nfn {}() {{\n{}}}\n", name, body)
+    }
+
+    /// Whether clap rejects the command line when none of `args` is given.
+    fn needs_arguments(args: &[ArgConfig], groups: &[ArgGroupConfig]) -> bool {
+        args.iter()
+            .any(|arg| Self::is_required(arg) || !arg.required_unless_present.is_empty())
+            || groups.iter().any(|group| group.required)
+    }
+
+    /// Whether clap rejects the command line when `arg` is missing.
+    pub(crate) fn is_required(arg: &ArgConfig) -> bool {
+        matches!(arg.kind, ArgKind::Option | ArgKind::Positional)
+            && arg.required
+            && arg.default_value.is_none()
+    }
+}
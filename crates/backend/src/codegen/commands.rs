@@ -0,0 +1,333 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::models::config::{Architecture, ArgConfig, ArgKind, CliConfig, CommandConfig, Layout};
+
+/// Keywords that cannot name a field, variant or module of the generated code.
+pub(crate) const RUST_KEYWORDS: [&str; 51] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Widest argument list rustfmt keeps on the line of an attribute.
+const ATTR_WIDTH: usize = 70;
+/// rustfmt's `max_width`.
+const MAX_WIDTH: usize = 100;
+
+impl CodeGenerator {
+    /// The `--long` flag of `arg`, without the dashes.
+    pub(crate) fn long_flag(arg: &ArgConfig) -> String {
+        arg.long
+            .clone()
+            .unwrap_or_else(|| Self::kebab_case(&arg.name))
+    }
+
+    pub(crate) fn is_kebab_case(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    }
+
+    pub(crate) fn is_snake_case(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+            && name.contains(|c: char| c.is_ascii_lowercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    }
+
+    /// Whether `long` can be pasted into `long = "..."` and typed after `--`.
+    pub(crate) fn is_long_flag(long: &str) -> bool {
+        long.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && long
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    /// Clap field definitions for the top-level arguments of `Cli`.
+    pub(crate) fn render_cli_args(config: &CliConfig) -> String {
+        let mut out = String::new();
+        for arg in &config.args {
+            out.push('\n');
+            out.push_str(&Self::render_arg(config, None, arg, "    ", "pub "));
+        }
+        if !config.commands.is_empty() {
+            let commands = match config.layout {
+                Layout::CommandsDir => "crate::commands::Commands",
+                _ => "Commands",
+            };
+            out.push_str(&format!(
+                "\n    #[command(subcommand)]\n    pub command: Option<{}>,\n",
+                commands
+            ));
+        }
+        // Separated by a blank line from the global options, if any
+        if config.global_options.is_empty() {
+            return out.trim().to_string();
+        }
+        out.trim_end().to_string()
+    }
+
+    /// The `Commands` enum holding one variant per configured subcommand.
+    ///
+    /// The dispatch architecture wraps each command's arguments in its own
+    /// `<Name>Command` struct so handlers can be implemented on it. The
+    /// commands-dir layout renders the enum into `src/commands/mod.rs` instead.
+    pub(crate) fn render_commands_enum(config: &CliConfig) -> String {
+        if config.commands.is_empty() || config.layout == Layout::CommandsDir {
+            return String::new();
+        }
+
+        let dispatch = config.architecture == Architecture::Dispatch;
+        let mut out = String::from("\n#[derive(clap::Subcommand, Debug)]\npub enum Commands {\n");
+        for (i, command) in config.commands.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            let payload = format!("{}Command", Self::variant_name(&command.name));
+            out.push_str(&Self::render_command_variant(
+                config,
+                command,
+                dispatch.then_some(payload.as_str()),
+            ));
+        }
+        out.push_str("}\n");
+
+        if dispatch {
+            for command in &config.commands {
+                out.push_str(&Self::render_command_struct(config, command));
+            }
+        }
+        out.trim_end().to_string()
+    }
+
+    /// A variant with the command's arguments inline, or wrapping `payload`.
+    pub(crate) fn render_command_variant(
+        config: &CliConfig,
+        command: &CommandConfig,
+        payload: Option<&str>,
+    ) -> String {
+        let variant = Self::variant_name(&command.name);
+        let mut out = Self::doc_comment(&command.description, "    ");
+        if Self::kebab_case(&variant) != command.name {
+            out.push_str(&format!("    #[command(name = \"{}\")]\n", command.name));
+        }
+        if let Some(help) = Self::render_after_long_help(config, command) {
+            out.push_str(&Self::render_attr("    ", "command", &[help]));
+        }
+
+        if let Some(payload) = payload {
+            out.push_str(&format!("    {}({}),\n", variant, payload));
+            return out;
+        }
+        out.push_str(&Self::render_arg_groups(&command.groups, "    "));
+        if command.args.is_empty() {
+            out.push_str(&format!("    {},\n", variant));
+            return out;
+        }
+
+        out.push_str(&format!("    {} {{\n", variant));
+        for (i, arg) in command.args.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str(&Self::render_arg(
+                config,
+                Some(command),
+                arg,
+                "        ",
+                "",
+            ));
+        }
+        out.push_str("    },\n");
+        out
+    }
+
+    /// The `#[derive(clap::Args)]` struct holding one command's arguments.
+    pub(crate) fn render_command_struct(config: &CliConfig, command: &CommandConfig) -> String {
+        let variant = Self::variant_name(&command.name);
+        let mut out = format!("\n/// Arguments of the `{}` command.\n", command.name);
+        out.push_str("#[derive(clap::Args, Debug)]\n");
+        out.push_str(&Self::render_arg_groups(&command.groups, ""));
+        if command.args.is_empty() {
+            out.push_str(&format!("pub struct {}Command {{}}\n", variant));
+            return out;
+        }
+
+        out.push_str(&format!("pub struct {}Command {{\n", variant));
+        for (i, arg) in command.args.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str(&Self::render_arg(
+                config,
+                Some(command),
+                arg,
+                "    ",
+                "pub ",
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// A documented `#[arg(...)]` field for `arg` of `command` (of the CLI
+    /// itself if `None`), indented by `indent`.
+    fn render_arg(
+        config: &CliConfig,
+        command: Option<&CommandConfig>,
+        arg: &ArgConfig,
+        indent: &str,
+        visibility: &str,
+    ) -> String {
+        let attrs = Self::arg_attrs(config, command, arg);
+        let mut out = Self::doc_comment(&arg.description, indent);
+        if !attrs.is_empty() {
+            out.push_str(&Self::render_attr(indent, "arg", &attrs));
+        }
+        out.push_str(&format!(
+            "{}{}{}: {},\n",
+            indent,
+            visibility,
+            arg.name,
+            Self::field_type(command, arg)
+        ));
+        out
+    }
+
+    /// The settings of the `#[arg(...)]` attribute of `arg`.
+    fn arg_attrs(
+        config: &CliConfig,
+        command: Option<&CommandConfig>,
+        arg: &ArgConfig,
+    ) -> Vec<String> {
+        let mut attrs = Vec::new();
+        if arg.kind != ArgKind::Positional {
+            if let Some(short) = arg.short {
+                attrs.push(format!("short = '{}'", short));
+            }
+            attrs.push(match &arg.long {
+                Some(long) => format!("long = \"{}\"", long),
+                None => "long".to_string(),
+            });
+        }
+        if arg.kind == ArgKind::Count {
+            attrs.push("action = clap::ArgAction::Count".to_string());
+        }
+        // A `Vec` field is optional to clap unless marked required
+        if Self::is_required(arg) && arg.multiple {
+            attrs.push("required = true".to_string());
+        }
+        if let Some(value_name) = &arg.value_name {
+            attrs.push(format!("value_name = \"{}\"", Self::escape(value_name)));
+        }
+        if let Some(default_value) = &arg.default_value {
+            attrs.push(format!(
+                "default_value = \"{}\"",
+                Self::escape(default_value)
+            ));
+        }
+        if arg.env {
+            attrs.push(format!("env = \"{}\"", Self::env_var(config, command, arg)));
+        }
+        attrs.extend(Self::relation_attrs(arg));
+        attrs
+    }
+
+    /// `#[name(args)]`, laid out the way rustfmt would.
+    fn render_attr(indent: &str, name: &str, args: &[String]) -> String {
+        let joined = args.join(", ");
+        let one_line = format!("{}#[{}({})]", indent, name, joined);
+        // rustfmt breaks lists longer than 70 columns one per line, but keeps
+        // a single argument on the line unless it exceeds the line width
+        let fits = match args.len() {
+            1 => one_line.chars().count() <= MAX_WIDTH,
+            _ => joined.chars().count() <= ATTR_WIDTH,
+        };
+        if fits {
+            return one_line + "\n";
+        }
+        format!(
+            "{indent}#[{name}(\n{indent}    {}\n{indent})]\n",
+            args.join(&format!(",\n{}    ", indent)),
+            indent = indent,
+            name = name
+        )
+    }
+
+    /// Rust type of the field generated for `arg` of `command` (of the CLI
+    /// itself if `None`).
+    pub(crate) fn field_type(command: Option<&CommandConfig>, arg: &ArgConfig) -> String {
+        match arg.kind {
+            ArgKind::Flag => "bool".to_string(),
+            ArgKind::Count => "u8".to_string(),
+            ArgKind::Option | ArgKind::Positional => {
+                let value_type = if arg.values.is_empty() {
+                    Self::qualified_type(&arg.value_type)
+                } else {
+                    Self::value_enum_name(command, arg)
+                };
+                if arg.multiple {
+                    format!("Vec<{}>", value_type)
+                } else if arg.required || arg.default_value.is_some() {
+                    value_type
+                } else {
+                    format!("Option<{}>", value_type)
+                }
+            }
+        }
+    }
+
+    /// Fully qualify common std types so `cli.rs` needs no extra imports.
+    fn qualified_type(value_type: &str) -> String {
+        match value_type {
+            "PathBuf" => "std::path::PathBuf".to_string(),
+            "OsString" => "std::ffi::OsString".to_string(),
+            other => other.to_string(),
+        }
+    }
+
+    pub(crate) fn doc_comment(text: &str, indent: &str) -> String {
+        text.lines()
+            .map(|line| format!("{}/// {}\n", indent, line).replace("/// \n", "///\n"))
+            .collect()
+    }
+
+    /// `add-item` -> `AddItem`
+    pub(crate) fn variant_name(name: &str) -> String {
+        name.split(['-', '_'])
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect()
+    }
+
+    /// `AddItem` -> `add-item`, `dry_run` -> `dry-run`
+    pub fn kebab_case(name: &str) -> String {
+        let mut out = String::new();
+        for (i, c) in name.chars().enumerate() {
+            if c.is_uppercase() {
+                if i > 0 {
+                    out.push('-');
+                }
+                out.extend(c.to_lowercase());
+            } else if c == '_' {
+                out.push('-');
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    pub(crate) fn escape(value: &str) -> String {
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+}
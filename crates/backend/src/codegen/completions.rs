@@ -0,0 +1,130 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::codegen::settings::SETTINGS_COMMAND;
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{ArgConfig, ArgKind, CliConfig, CommandConfig};
+
+/// Name of the built-in subcommand printing shell completions.
+pub const COMPLETIONS_COMMAND: &str = "completions";
+
+impl CodeGenerator {
+    /// `config` with the built-in subcommands it enables appended to its
+    /// commands, so every layout renders them like configured ones.
+    pub(crate) fn with_builtin_commands(config: &CliConfig) -> CliConfig {
+        let mut config = config.clone();
+        if config.completions {
+            config.commands.push(CommandConfig {
+                name: COMPLETIONS_COMMAND.to_string(),
+                description: "Print shell completions to stdout".to_string(),
+                args: vec![ArgConfig {
+                    description: "Shell to generate completions for".to_string(),
+                    kind: ArgKind::Positional,
+                    value_type: "clap_complete::Shell".to_string(),
+                    required: true,
+                    ..ArgConfig::new("shell")
+                }],
+                groups: Vec::new(),
+                examples: Vec::new(),
+            });
+        }
+        if config.settings {
+            config.commands.push(CommandConfig {
+                name: SETTINGS_COMMAND.to_string(),
+                description: "Print the effective settings and their sources".to_string(),
+                args: Vec::new(),
+                groups: Vec::new(),
+                examples: Vec::new(),
+            });
+        }
+        config
+    }
+
+    pub(crate) fn is_completions_command(config: &CliConfig, command: &CommandConfig) -> bool {
+        config.completions && command.name == COMPLETIONS_COMMAND
+    }
+
+    /// Early exit for `completions <shell>` in `main.rs` of the simple
+    /// architecture, where `main` handles the parsed commands itself.
+    pub(crate) fn render_main_completions(config: &CliConfig) -> String {
+        if !config.completions {
+            return String::new();
+        }
+        format!(
+            r#"
+    if let Some(Commands::Completions {{ shell }}) = &cli.command {{
+        let mut command = <Cli as clap::CommandFactory>::command();
+        let name = command.get_name().to_string();
+        clap_complete::generate(*shell, &mut command, name, &mut std::io::stdout());
+        return{};
+    }}"#,
+            Self::render_main_success(config)
+        )
+    }
+
+    /// Items `main.rs` imports from the `cli` module.
+    pub(crate) fn render_cli_imports(config: &CliConfig) -> String {
+        if config.completions || config.settings {
+            "{Cli, Commands}".to_string()
+        } else {
+            "Cli".to_string()
+        }
+    }
+
+    /// `CommandHandler` implementation of the dispatch architecture.
+    pub(crate) fn render_completions_handler(config: &CliConfig) -> String {
+        format!(
+            r#"
+impl CommandHandler for crate::config::CompletionsCommand {{
+    fn handle(&self, _config: &CliConfig) -> {} {{
+        let mut command = <CliConfig as clap::CommandFactory>::command();
+        let name = command.get_name().to_string();
+        clap_complete::generate(self.shell, &mut command, name, &mut std::io::stdout());
+        Ok(())
+    }}
+}}
+"#,
+            Self::result_type(config)
+        )
+    }
+
+    /// Installation instructions appended to `long-help.txt`.
+    pub(crate) fn render_completions_help(config: &CliConfig) -> String {
+        if !config.completions {
+            return String::new();
+        }
+        format!(
+            r#"SHELL COMPLETIONS:
+    # Bash
+    {name} completions bash > ~/.local/share/bash-completion/completions/{name}
+
+    # Zsh (with ~/.zfunc in $fpath)
+    {name} completions zsh > ~/.zfunc/_{name}
+
+    # Fish
+    {name} completions fish > ~/.config/fish/completions/{name}.fish
+
+    # PowerShell
+    {name} completions powershell >> $PROFILE
+"#,
+            name = config.name
+        )
+    }
+
+    pub(crate) fn validate_completions(config: &CliConfig) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if let Some(i) = config
+            .commands
+            .iter()
+            .position(|command| command.name == COMPLETIONS_COMMAND)
+            && config.completions
+        {
+            errors.push(ValidationError::new(
+                format!("/commands/{}/name", i),
+                format!(
+                    "Command name '{}' is reserved when completions are enabled",
+                    COMPLETIONS_COMMAND
+                ),
+            ));
+        }
+        errors
+    }
+}
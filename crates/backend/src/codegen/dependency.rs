@@ -0,0 +1,182 @@
+use crate::codegen::generators::{CodeGenerator, FILE_MODE, GeneratedFile, SCRIPT_MODE};
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{CliConfig, Layout, SwCliDependency};
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+/// Where the submodule and vendored modes place sw-cli inside the project.
+pub const SW_CLI_DIR: &str = "lib/sw-cli";
+
+impl CodeGenerator {
+    /// The `sw-cli = ...` line used in both `[dependencies]` and
+    /// `[build-dependencies]`, or `None` for standalone projects.
+    pub(crate) fn render_sw_cli_dependency(config: &CliConfig) -> Option<String> {
+        let spec = match &config.sw_cli_dependency {
+            SwCliDependency::Standalone => return None,
+            SwCliDependency::Submodule | SwCliDependency::Vendored { .. } => {
+                format!("{{ path = \"{}\" }}", SW_CLI_DIR)
+            }
+            SwCliDependency::Git { rev, tag } => {
+                let mut spec = format!("{{ git = \"{}\"", config.sw_cli_url);
+                if let Some(rev) = rev {
+                    spec.push_str(&format!(", rev = \"{}\"", rev));
+                }
+                if let Some(tag) = tag {
+                    spec.push_str(&format!(", tag = \"{}\"", tag));
+                }
+                spec + " }"
+            }
+            SwCliDependency::Path { path } => format!("{{ path = \"{}\" }}", path),
+            SwCliDependency::Registry { version } => format!("\"{}\"", version),
+        };
+        Some(format!("sw-cli = {}", spec))
+    }
+
+    /// Path of the crate providing `check_version_flag()` and `version!()`.
+    pub(crate) fn version_crate(config: &CliConfig) -> String {
+        match (&config.sw_cli_dependency, config.layout) {
+            (SwCliDependency::Standalone, Layout::SingleFile | Layout::Workspace) => {
+                "crate".to_string()
+            }
+            (SwCliDependency::Standalone, _) => config.name.replace("-", "_"),
+            _ => "sw_cli".to_string(),
+        }
+    }
+
+    /// Expression for the short or long help text used in `cli.rs`.
+    pub(crate) fn help_text_expr(config: &CliConfig, long: bool) -> String {
+        match (&config.sw_cli_dependency, long) {
+            (SwCliDependency::Standalone, false) => "include_str!(\"short-help.txt\")".to_string(),
+            (SwCliDependency::Standalone, true) => "include_str!(\"long-help.txt\")".to_string(),
+            (_, false) => "sw_cli::short_help!()".to_string(),
+            (_, true) => "sw_cli::long_help!()".to_string(),
+        }
+    }
+
+    /// The steps of `scripts/setup.sh` that make sw-cli available.
+    pub(crate) fn render_sw_cli_setup(config: &CliConfig) -> String {
+        match &config.sw_cli_dependency {
+            SwCliDependency::Submodule => format!(
+                r#"# Add sw-cli submodule
+echo "Adding sw-cli submodule..."
+if [ -d "{dir}" ]; then
+    echo "sw-cli submodule already exists, skipping..."
+else
+    git submodule add {url} {dir}
+fi
+
+# Initialize and update submodules
+echo "Updating submodules..."
+git submodule update --init --recursive"#,
+                dir = SW_CLI_DIR,
+                url = config.sw_cli_url
+            ),
+            SwCliDependency::Git { .. } | SwCliDependency::Registry { .. } => {
+                "# sw-cli is fetched by cargo on the first build\necho \"sw-cli will be fetched by cargo on the first build\"".to_string()
+            }
+            SwCliDependency::Path { path } => format!(
+                r#"# Check the sw-cli checkout referenced from Cargo.toml
+if [ ! -f "{path}/Cargo.toml" ]; then
+    echo "Error: sw-cli checkout not found at {path}" >&2
+    exit 1
+fi
+echo "Using sw-cli checkout at {path}""#,
+                path = path
+            ),
+            SwCliDependency::Vendored { .. } => format!(
+                "# sw-cli sources are vendored in {dir}\necho \"Using vendored sw-cli in {dir}\"",
+                dir = SW_CLI_DIR
+            ),
+            SwCliDependency::Standalone => String::new(),
+        }
+    }
+
+    /// The steps of `scripts/build.sh` that refresh sw-cli before building.
+    pub(crate) fn render_sw_cli_update(config: &CliConfig) -> String {
+        match &config.sw_cli_dependency {
+            SwCliDependency::Submodule => "# Always ensure submodules are initialized and updated to latest\necho \"Updating git submodules to latest...\"\ngit submodule update --init --recursive --remote --merge\n".to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// Copies of the sw-cli sources for the vendored mode, placed under [`SW_CLI_DIR`].
+    pub(crate) fn vendored_files(
+        config: &CliConfig,
+    ) -> Result<Vec<GeneratedFile>, Box<dyn std::error::Error>> {
+        let SwCliDependency::Vendored { source } = &config.sw_cli_dependency else {
+            return Ok(Vec::new());
+        };
+
+        let source_dir = Path::new(source);
+        if !source_dir.join("Cargo.toml").exists() {
+            return Err(format!("No sw-cli checkout found at {}", source).into());
+        }
+
+        let mut files = Vec::new();
+        for path in Self::walk_files(source_dir)? {
+            let relative = path
+                .strip_prefix(source_dir)?
+                .to_string_lossy()
+                .replace('\\', "/");
+            let bytes = fs::read(&path)
+                .map_err(|e| format!("Failed to vendor {}: {}", path.display(), e))?;
+            // Generated files are text; binary ones are left for the user to copy
+            let Ok(content) = String::from_utf8(bytes) else {
+                eprintln!("Warning: skipped vendoring binary file {}", path.display());
+                continue;
+            };
+            let executable = fs::metadata(&path)?.permissions().mode() & 0o111 != 0;
+            files.push(GeneratedFile {
+                path: format!("{}/{}", SW_CLI_DIR, relative),
+                content,
+                mode: if executable { SCRIPT_MODE } else { FILE_MODE },
+            });
+        }
+
+        Ok(files)
+    }
+
+    pub(crate) fn validate_dependency(config: &CliConfig) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        let error = match &config.sw_cli_dependency {
+            SwCliDependency::Git {
+                rev: Some(_),
+                tag: Some(_),
+            } => Some((
+                "Git/tag",
+                "sw-cli git dependency cannot pin both a rev and a tag",
+            )),
+            SwCliDependency::Path { path } if path.is_empty() => {
+                Some(("Path/path", "sw-cli path cannot be empty"))
+            }
+            SwCliDependency::Registry { version } if version.is_empty() => Some((
+                "Registry/version",
+                "sw-cli registry version cannot be empty",
+            )),
+            SwCliDependency::Vendored { source } if source.is_empty() => {
+                Some(("Vendored/source", "sw-cli vendoring source cannot be empty"))
+            }
+            _ => None,
+        };
+        if let Some((field, message)) = error {
+            errors.push(ValidationError::new(
+                format!("/sw_cli_dependency/{}", field),
+                message,
+            ));
+        }
+
+        if matches!(
+            config.sw_cli_dependency,
+            SwCliDependency::Submodule | SwCliDependency::Git { .. }
+        ) && config.sw_cli_url.is_empty()
+        {
+            errors.push(ValidationError::new(
+                "/sw_cli_url",
+                "sw-cli URL cannot be empty",
+            ));
+        }
+
+        errors
+    }
+}
@@ -0,0 +1,221 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::models::config::{Architecture, CliConfig, ErrorHandling, Layout};
+
+impl CodeGenerator {
+    /// `use` declarations at the top of `main.rs`, sorted as rustfmt does.
+    pub(crate) fn render_main_imports(config: &CliConfig) -> String {
+        let lib = Self::lib_crate(config);
+        let error_handling = config.error_handling != ErrorHandling::None;
+        let mut imports = Vec::new();
+        // `parse_args` replaces the parser's own `parse`
+        if !config.sysexits || config.layout == Layout::SingleFile {
+            imports.push("use clap::Parser;".to_string());
+        }
+        match (config.layout, config.architecture) {
+            (Layout::SingleFile, _) => {
+                let global_imports = Self::render_global_imports(config);
+                if !global_imports.is_empty() {
+                    imports.push(global_imports);
+                }
+            }
+            (Layout::CommandsDir, _) => imports.push(format!("use {}::cli::Cli;", lib)),
+            (_, Architecture::Simple) => imports.push(format!(
+                "use {}::cli::{};",
+                lib,
+                Self::render_cli_imports(config)
+            )),
+            (_, Architecture::Dispatch) => {
+                imports.push(format!("use {}::config::CliConfig;", lib));
+                imports.push(format!("use {}::dispatch::CliDispatcher;", lib));
+            }
+        }
+        if config.settings
+            && config.architecture == Architecture::Simple
+            && !matches!(config.layout, Layout::SingleFile | Layout::CommandsDir)
+        {
+            imports.push(format!("use {}::settings::Settings;", lib));
+        }
+        if error_handling {
+            imports.push("use std::process::ExitCode;".to_string());
+            match (config.layout, config.architecture) {
+                // main.rs declares the error module itself
+                (Layout::SingleFile, _) => {}
+                (Layout::CommandsDir, _) | (_, Architecture::Dispatch) => {
+                    imports.push(format!("use {}::error;", lib))
+                }
+                (_, Architecture::Simple) => {
+                    imports.push(format!("use {}::error::{{self, Result}};", lib))
+                }
+            }
+        }
+        imports.sort();
+        imports.join("\n")
+    }
+
+    /// Return type of `main`.
+    pub(crate) fn render_main_return(config: &CliConfig) -> String {
+        match config.error_handling {
+            ErrorHandling::None => String::new(),
+            _ => " -> ExitCode".to_string(),
+        }
+    }
+
+    /// Value of the early `return`s of `main` after printing the version or
+    /// completions.
+    pub(crate) fn render_main_success(config: &CliConfig) -> String {
+        match config.error_handling {
+            ErrorHandling::None => String::new(),
+            _ => " ExitCode::SUCCESS".to_string(),
+        }
+    }
+
+    /// Early exit of `main` after reporting an error.
+    pub(crate) fn main_failure(config: &CliConfig) -> &'static str {
+        match config.error_handling {
+            ErrorHandling::None => "std::process::exit(1);",
+            _ => "return ExitCode::FAILURE;",
+        }
+    }
+
+    /// Expression parsing the command line in `main`.
+    pub(crate) fn render_parse_cli(config: &CliConfig) -> String {
+        let parser = Self::parser_path(config).1;
+        if config.sysexits {
+            format!("error::parse_args::<{}>()", parser)
+        } else {
+            format!("{}::parse()", parser)
+        }
+    }
+
+    /// The rest of `main` after parsing: running the commands and turning
+    /// their errors into an exit code.
+    pub(crate) fn render_main_run(config: &CliConfig) -> String {
+        match (config.layout, config.architecture) {
+            (Layout::CommandsDir, _) => Self::render_commands_run(config),
+            (Layout::SingleFile, _) | (_, Architecture::Simple) => Self::render_simple_run(config),
+            (_, Architecture::Dispatch) => Self::render_dispatch_run(config),
+        }
+    }
+
+    /// Match arm of `main` reporting an error and mapping it to an exit code.
+    fn render_error_report(config: &CliConfig) -> String {
+        format!(
+            r#"        Err(e) => {{
+            eprintln!("Error: {}", e);
+            error::exit_code(&e)
+        }}"#,
+            match config.error_handling {
+                // Include the context added to the error
+                ErrorHandling::Anyhow => "{:#}",
+                _ => "{}",
+            }
+        )
+    }
+
+    /// Running the selected command of the commands-dir layout.
+    fn render_commands_run(config: &CliConfig) -> String {
+        let default_command = format!(
+            r#"            // Default behavior when no subcommand is provided
+            println!("{}");
+            println!("Use --help for more information.");"#,
+            config.short_description
+        );
+        if config.error_handling == ErrorHandling::None {
+            return format!(
+                r#"
+    match &cli.command {{
+        Some(command) => {{
+            if let Err(e) = command.run(&cli) {{
+                eprintln!("Error: {{}}", e);
+                std::process::exit(1);
+            }}
+        }}
+        None => {{
+{}
+        }}
+    }}"#,
+                default_command
+            );
+        }
+        format!(
+            r#"
+    let result = match &cli.command {{
+        Some(command) => command.run(&cli),
+        None => {{
+{default_command}
+            Ok(())
+        }}
+    }};
+    match result {{
+        Ok(()) => ExitCode::SUCCESS,
+{report}
+    }}"#,
+            default_command = default_command,
+            report = Self::render_error_report(config)
+        )
+    }
+
+    /// The placeholder logic of the simple architecture, moved to a `run`
+    /// function when errors are mapped to exit codes.
+    fn render_simple_run(config: &CliConfig) -> String {
+        let (args, params, settings) = if config.settings {
+            (
+                "&cli, &settings",
+                "cli: &Cli, settings: &Settings",
+                "\n    println!(\"Settings: {:?}\", settings);",
+            )
+        } else {
+            ("&cli", "cli: &Cli", "")
+        };
+        if config.error_handling == ErrorHandling::None {
+            return format!(
+                r#"
+    // TODO: Implement your CLI logic here
+    println!("{name} is running!");
+    println!("Config: {{:?}}", cli);{settings}"#,
+                name = config.name,
+                settings = settings
+            );
+        }
+        format!(
+            r#"
+    match run({args}) {{
+        Ok(()) => ExitCode::SUCCESS,
+{report}
+    }}
+}}
+
+/// The logic of {name}; `main` reports errors and maps them to exit codes.
+fn run({params}) -> Result<()> {{
+    // TODO: Implement your CLI logic here
+    println!("{name} is running!");
+    println!("Config: {{:?}}", cli);{settings}
+    Ok(())"#,
+            args = args,
+            params = params,
+            settings = settings,
+            report = Self::render_error_report(config),
+            name = config.name
+        )
+    }
+
+    /// Handing the parsed configuration to the dispatcher.
+    fn render_dispatch_run(config: &CliConfig) -> String {
+        if config.error_handling == ErrorHandling::None {
+            return r#"
+    if let Err(e) = CliDispatcher::dispatch(&config) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }"#
+            .to_string();
+        }
+        format!(
+            r#"
+    match CliDispatcher::dispatch(&config) {{
+        Ok(()) => ExitCode::SUCCESS,
+{}
+    }}"#,
+            Self::render_error_report(config)
+        )
+    }
+}
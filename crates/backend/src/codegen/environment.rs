@@ -0,0 +1,155 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{ArgConfig, ArgKind, CliConfig, CommandConfig};
+use std::collections::HashSet;
+
+impl CodeGenerator {
+    /// Prefix of the environment variables of the generated CLI, e.g.
+    /// `MY_CLI_`.
+    pub(crate) fn env_prefix(config: &CliConfig) -> String {
+        format!("{}_", config.name.replace('-', "_").to_uppercase())
+    }
+
+    /// Environment variable `arg` of `command` (of the CLI itself if `None`)
+    /// falls back to: `MY_CLI_OUTPUT`, or `MY_CLI_ADD_PRIORITY`.
+    pub(crate) fn env_var(
+        config: &CliConfig,
+        command: Option<&CommandConfig>,
+        arg: &ArgConfig,
+    ) -> String {
+        let mut var = Self::env_prefix(config);
+        if let Some(command) = command {
+            var.push_str(&command.name.replace('-', "_").to_uppercase());
+            var.push('_');
+        }
+        var.push_str(&arg.name.to_uppercase());
+        var
+    }
+
+    /// `ARG_ENV_VARS` of the settings module: the variables arguments fall
+    /// back to share the settings prefix, but are not settings.
+    pub(crate) fn render_arg_env_vars(config: &CliConfig) -> String {
+        let vars: Vec<String> = Self::env_args(config)
+            .into_iter()
+            .map(|(command, arg)| format!("\"{}\"", Self::env_var(config, command, arg)))
+            .collect();
+        let one_line = format!("pub const ARG_ENV_VARS: &[&str] = &[{}];", vars.join(", "));
+        if one_line.chars().count() <= 100 {
+            return one_line;
+        }
+        let items: String = vars.iter().map(|var| format!("    {},\n", var)).collect();
+        format!("pub const ARG_ENV_VARS: &[&str] = &[\n{}];", items)
+    }
+
+    /// Arguments bound to an environment variable, with their command.
+    fn env_args(config: &CliConfig) -> Vec<(Option<&CommandConfig>, &ArgConfig)> {
+        let top_level = config.args.iter().map(|arg| (None, arg));
+        let commands = config
+            .commands
+            .iter()
+            .flat_map(|command| command.args.iter().map(move |arg| (Some(command), arg)));
+        top_level
+            .chain(commands)
+            .filter(|(_, arg)| arg.env)
+            .collect()
+    }
+
+    /// Features of the clap dependency; `env` is needed by `#[arg(env)]`.
+    pub(crate) fn render_clap_features(config: &CliConfig) -> String {
+        if Self::env_args(config).is_empty() {
+            "[\"derive\"]".to_string()
+        } else {
+            "[\"derive\", \"env\"]".to_string()
+        }
+    }
+
+    /// The ENVIRONMENT section of `long-help.txt`.
+    pub(crate) fn render_environment_help(config: &CliConfig) -> String {
+        let args = Self::env_args(config);
+        if args.is_empty() {
+            return String::new();
+        }
+        let mut out = String::from("ENVIRONMENT:\n");
+        for (command, arg) in args {
+            let usage = match command {
+                Some(command) => format!("{} {}", command.name, Self::usage_arg(arg)),
+                None => Self::usage_arg(arg),
+            };
+            let description = arg.description.lines().next().unwrap_or_default();
+            out.push_str(&format!(
+                "    {}\n            {}\n",
+                Self::env_var(config, command, arg),
+                format!("{} ({})", description, usage).trim()
+            ));
+        }
+        out
+    }
+
+    /// Call appending the ENVIRONMENT section to each man page.
+    pub(crate) fn render_man_environment(config: &CliConfig) -> String {
+        if Self::env_args(config).is_empty() {
+            return String::new();
+        }
+        "    page.extend_from_slice(environment_section(command).as_bytes());".to_string()
+    }
+
+    /// `environment_section` of `examples/mangen.rs`, listing the variables
+    /// the arguments of a command fall back to.
+    pub(crate) fn render_man_environment_fn(config: &CliConfig) -> String {
+        if Self::env_args(config).is_empty() {
+            return String::new();
+        }
+        r#"
+/// ENVIRONMENT section of the page of `command`, if any of its arguments
+/// falls back to an environment variable.
+fn environment_section(command: &Command) -> String {
+    let vars: Vec<_> = command
+        .get_arguments()
+        .filter_map(|arg| {
+            // Variables of undocumented arguments are listed all the same
+            let help = arg.get_help().map(ToString::to_string).unwrap_or_default();
+            Some((arg.get_env()?, help))
+        })
+        .collect();
+    if vars.is_empty() {
+        return String::new();
+    }
+    let mut roff = Roff::new();
+    roff.control("SH", ["ENVIRONMENT"]);
+    for (var, help) in vars {
+        roff.control("TP", [])
+            .text([clap_mangen::roff::bold(var.to_string_lossy())])
+            .text([roman(help)]);
+    }
+    roff.to_roff()
+}"#
+        .to_string()
+    }
+
+    pub(crate) fn validate_env(config: &CliConfig) -> Vec<ValidationError> {
+        let mut vars = HashSet::new();
+        let mut errors = Vec::new();
+        for (command, path, arg) in Self::located_args(config) {
+            if !arg.env {
+                continue;
+            }
+            if arg.kind == ArgKind::Count {
+                errors.push(ValidationError::new(
+                    format!("{}/env", path),
+                    format!(
+                        "Count argument '{}' cannot fall back to an environment variable",
+                        arg.name
+                    ),
+                ));
+            }
+            let var = Self::env_var(config, command, arg);
+            if !vars.insert(var.clone()) {
+                errors.push(ValidationError::new(
+                    format!("{}/env", path),
+                    format!("Duplicate environment variable {}", var),
+                ));
+            }
+        }
+        errors
+    }
+}
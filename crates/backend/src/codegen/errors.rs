@@ -0,0 +1,335 @@
+use crate::codegen::generators::{CodeGenerator, FILE_MODE};
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{CliConfig, ErrorHandling, Layout};
+use std::collections::HashSet;
+
+/// The sysexits(3) codes, as (variant, code, description).
+const SYSEXITS: [(&str, u8, &str); 15] = [
+    ("Usage", 64, "Command line usage error"),
+    ("DataErr", 65, "Data format error"),
+    ("NoInput", 66, "Cannot open input"),
+    ("NoUser", 67, "Addressee unknown"),
+    ("NoHost", 68, "Host name unknown"),
+    ("Unavailable", 69, "Service unavailable"),
+    ("Software", 70, "Internal software error"),
+    ("OsErr", 71, "System error"),
+    ("OsFile", 72, "Critical OS file missing"),
+    ("CantCreat", 73, "Cannot create output file"),
+    ("IoErr", 74, "Input/output error"),
+    ("TempFail", 75, "Temporary failure, try again later"),
+    ("Protocol", 76, "Remote error in protocol"),
+    ("NoPerm", 77, "Permission denied"),
+    ("Config", 78, "Configuration error"),
+];
+
+/// `Exit` variants every table starts with; they get no `Error` variant.
+const BUILTIN_EXITS: [&str; 3] = ["Success", "Failure", "Usage"];
+
+/// Variant names the `Error` enum of the thiserror scaffold uses itself.
+const RESERVED_ERRORS: [&str; 3] = ["Io", "Other", "Settings"];
+
+/// A row of the exit-code table.
+struct ExitEntry {
+    variant: String,
+    code: u8,
+    description: String,
+}
+
+impl ExitEntry {
+    fn new(variant: &str, code: u8, description: &str) -> Self {
+        ExitEntry {
+            variant: variant.to_string(),
+            code,
+            description: description.to_string(),
+        }
+    }
+}
+
+impl CodeGenerator {
+    /// `error.rs` of the library crate (of the binary in the single-file
+    /// layout), as (template name, output path, mode).
+    pub(crate) fn error_outputs(
+        config: &CliConfig,
+        lib_src: &str,
+    ) -> Vec<(&'static str, String, u32)> {
+        let template = match config.error_handling {
+            ErrorHandling::None => return Vec::new(),
+            ErrorHandling::Thiserror => "error_thiserror.rs",
+            ErrorHandling::Anyhow => "error_anyhow.rs",
+        };
+        vec![(template, format!("{}/error.rs", lib_src), FILE_MODE)]
+    }
+
+    /// Exit codes every generated CLI has: success, failure and usage
+    /// errors, plus the other sysexits codes if requested.
+    fn builtin_exits(config: &CliConfig) -> Vec<ExitEntry> {
+        let mut exits = vec![
+            ExitEntry::new("Success", 0, "Success"),
+            ExitEntry::new("Failure", 1, "Error occurred"),
+        ];
+        if config.sysexits {
+            exits.extend(
+                SYSEXITS.iter().map(|(variant, code, description)| {
+                    ExitEntry::new(variant, *code, description)
+                }),
+            );
+        } else {
+            exits.push(ExitEntry::new("Usage", 2, "Invalid command-line usage"));
+        }
+        exits
+    }
+
+    /// The built-in and the configured exit codes, by code.
+    fn exit_table(config: &CliConfig) -> Vec<ExitEntry> {
+        let mut table = Self::builtin_exits(config);
+        table.extend(config.exit_codes.iter().map(|exit_code| {
+            ExitEntry::new(
+                &Self::variant_name(&exit_code.name),
+                exit_code.code,
+                &exit_code.description,
+            )
+        }));
+        table.sort_by_key(|entry| entry.code);
+        table
+    }
+
+    /// Code clap's usage errors exit with.
+    pub(crate) fn usage_exit_code(config: &CliConfig) -> u8 {
+        if config.sysexits { 64 } else { 2 }
+    }
+
+    /// The EXIT CODES entries of `long-help.txt`.
+    pub(crate) fn render_exit_codes_help(config: &CliConfig) -> String {
+        if config.error_handling == ErrorHandling::None {
+            return "    0 - Success\n    1 - Error occurred".to_string();
+        }
+        let table = Self::exit_table(config);
+        let width = table
+            .iter()
+            .map(|entry| entry.code.to_string().len())
+            .max()
+            .unwrap_or(1);
+        table
+            .iter()
+            .map(|entry| {
+                format!(
+                    "    {:>width$} - {}",
+                    entry.code,
+                    entry.description,
+                    width = width
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub(crate) fn render_exit_variants(config: &CliConfig) -> String {
+        Self::exit_table(config)
+            .iter()
+            .map(|entry| {
+                format!(
+                    "    /// {}\n    {} = {},",
+                    entry.description, entry.variant, entry.code
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Exit codes with an `Error` variant of their own.
+    fn error_entries(config: &CliConfig) -> Vec<ExitEntry> {
+        Self::exit_table(config)
+            .into_iter()
+            .filter(|entry| !BUILTIN_EXITS.contains(&entry.variant.as_str()))
+            .collect()
+    }
+
+    /// `Error` variants of the thiserror scaffold: loading the settings,
+    /// then one per exit code.
+    pub(crate) fn render_error_variants(config: &CliConfig) -> String {
+        let mut variants = Vec::new();
+        if config.settings {
+            variants.push(
+                "    /// Loading the settings failed\n    #[error(transparent)]\n    Settings(#[from] crate::settings::SettingsError),"
+                    .to_string(),
+            );
+        }
+        variants.extend(Self::error_entries(config).iter().map(|entry| {
+            format!(
+                "    /// {}\n    #[error(\"{{0}}\")]\n    {}(String),",
+                entry.description, entry.variant
+            )
+        }));
+        variants.join("\n")
+    }
+
+    /// Arms of `Error::exit` for the variants of `render_error_variants`.
+    pub(crate) fn render_error_exits(config: &CliConfig) -> String {
+        let mut exits = Vec::new();
+        if config.settings {
+            let exit = if config.sysexits { "Config" } else { "Failure" };
+            exits.push(format!("            Error::Settings(_) => Exit::{},", exit));
+        }
+        exits.extend(Self::error_entries(config).iter().map(|entry| {
+            format!(
+                "            Error::{variant}(_) => Exit::{variant},",
+                variant = entry.variant
+            )
+        }));
+        exits.join("\n")
+    }
+
+    /// Exit code of I/O errors: `EX_IOERR` with sysexits, else a plain failure.
+    pub(crate) fn render_io_exit(config: &CliConfig) -> String {
+        if config.sysexits { "IoErr" } else { "Failure" }.to_string()
+    }
+
+    /// I/O errors in the causes of an anyhow error exit with `EX_IOERR`.
+    pub(crate) fn render_io_exit_check(config: &CliConfig) -> String {
+        if !config.sysexits {
+            return String::new();
+        }
+        "        if cause.is::<std::io::Error>() {\n            return Exit::IoErr.into();\n        }"
+            .to_string()
+    }
+
+    /// Settings errors in the causes of an anyhow error exit with
+    /// `EX_CONFIG`, as `Error::Settings` does with thiserror.
+    pub(crate) fn render_settings_exit_check(config: &CliConfig) -> String {
+        if !config.sysexits || !config.settings {
+            return String::new();
+        }
+        "        if cause.is::<crate::settings::SettingsError>() {\n            return Exit::Config.into();\n        }"
+            .to_string()
+    }
+
+    /// `parse_args`, replacing clap's exit code 2 on usage errors by
+    /// `EX_USAGE`.
+    pub(crate) fn render_parse_args(config: &CliConfig) -> String {
+        if !config.sysexits {
+            return String::new();
+        }
+        r#"
+/// Parse the command line like `Parser::parse`, but exit with
+/// `Exit::Usage` rather than clap's 2 on usage errors.
+pub fn parse_args<P: clap::Parser>() -> P {
+    P::try_parse().unwrap_or_else(|e| {
+        let _ = e.print();
+        let code = if e.use_stderr() {
+            Exit::Usage as i32
+        } else {
+            0
+        };
+        std::process::exit(code)
+    })
+}"#
+        .to_string()
+    }
+
+    /// The single-file layout compiles the error module into the binary,
+    /// where the exit codes nobody returns yet would be dead code.
+    pub(crate) fn render_error_lints(config: &CliConfig) -> String {
+        if config.layout == Layout::SingleFile {
+            "\n// Not every exit code is returned by the commands yet\n#![allow(dead_code)]"
+                .to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Dependency of the error module.
+    pub(crate) fn render_error_dependency(config: &CliConfig) -> Option<String> {
+        match config.error_handling {
+            ErrorHandling::None => None,
+            ErrorHandling::Thiserror => Some("thiserror = \"2\"".to_string()),
+            ErrorHandling::Anyhow => Some("anyhow = \"1\"".to_string()),
+        }
+    }
+
+    /// Dependencies of the core crate of the workspace layout, which holds
+    /// the error and settings modules.
+    pub(crate) fn render_core_dependencies(config: &CliConfig) -> String {
+        let mut dependencies = Vec::new();
+        match config.error_handling {
+            ErrorHandling::None => {}
+            ErrorHandling::Thiserror => dependencies.push("thiserror.workspace = true"),
+            ErrorHandling::Anyhow => dependencies.push("anyhow.workspace = true"),
+        }
+        if config.settings {
+            dependencies.push("toml.workspace = true");
+        }
+        dependencies.join("\n")
+    }
+
+    /// Return type of command handlers.
+    pub(crate) fn result_type(config: &CliConfig) -> &'static str {
+        match config.error_handling {
+            ErrorHandling::None => "Result<(), Box<dyn Error>>",
+            _ => "Result<()>",
+        }
+    }
+
+    /// Imports of the modules under `src/commands/`, with the error type
+    /// their handlers return.
+    pub(crate) fn render_command_imports(config: &CliConfig) -> String {
+        Self::handler_imports(config, "use crate::cli::Cli;")
+    }
+
+    /// Imports of `dispatch.rs`, with the error type its handlers return.
+    pub(crate) fn render_dispatch_imports(config: &CliConfig) -> String {
+        Self::handler_imports(config, "use crate::config::{CliConfig, Commands};")
+    }
+
+    fn handler_imports(config: &CliConfig, parser_import: &str) -> String {
+        match config.error_handling {
+            ErrorHandling::None => format!("use std::error::Error;\n\n{}", parser_import),
+            _ => format!("{}\nuse crate::error::Result;", parser_import),
+        }
+    }
+
+    pub(crate) fn validate_error_handling(config: &CliConfig) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if config.error_handling == ErrorHandling::None
+            && (config.sysexits || !config.exit_codes.is_empty())
+        {
+            errors.push(ValidationError::new(
+                "/error_handling",
+                "Exit codes need an error handling scaffold",
+            ));
+        }
+
+        let builtin = Self::builtin_exits(config);
+        let mut variants: HashSet<String> = builtin.iter().map(|e| e.variant.clone()).collect();
+        let mut codes: HashSet<u8> = builtin.iter().map(|e| e.code).collect();
+        for (i, exit_code) in config.exit_codes.iter().enumerate() {
+            let path = format!("/exit_codes/{}", i);
+            let variant = Self::variant_name(&exit_code.name);
+            if !variant.starts_with(|c: char| c.is_ascii_alphabetic())
+                || !variant.chars().all(|c| c.is_ascii_alphanumeric())
+            {
+                errors.push(ValidationError::new(
+                    format!("{}/name", path),
+                    format!("Invalid exit code name '{}'", exit_code.name),
+                ));
+            } else if RESERVED_ERRORS.contains(&variant.as_str()) || !variants.insert(variant) {
+                errors.push(ValidationError::new(
+                    format!("{}/name", path),
+                    format!(
+                        "Exit code name '{}' is reserved or already used",
+                        exit_code.name
+                    ),
+                ));
+            }
+            if !codes.insert(exit_code.code) {
+                errors.push(ValidationError::new(
+                    format!("{}/code", path),
+                    format!(
+                        "Exit code {} of '{}' is already used",
+                        exit_code.code, exit_code.name
+                    ),
+                ));
+            }
+        }
+        errors
+    }
+}
@@ -0,0 +1,285 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{CliConfig, CommandConfig, ExampleConfig};
+
+/// Widest argument list rustfmt keeps on the line of a function call.
+const CALL_WIDTH: usize = 60;
+
+impl CodeGenerator {
+    /// The configured examples of the CLI followed by those of its commands.
+    fn all_examples(config: &CliConfig) -> Vec<&ExampleConfig> {
+        config
+            .examples
+            .iter()
+            .chain(config.commands.iter().flat_map(|command| &command.examples))
+            .collect()
+    }
+
+    /// `# description` lines followed by the command line, indented by
+    /// `indent`.
+    pub(crate) fn format_example(
+        config: &CliConfig,
+        example: &ExampleConfig,
+        indent: &str,
+    ) -> String {
+        let mut out: String = example
+            .description
+            .lines()
+            .map(|line| format!("{}# {}\n", indent, line.trim()).replace("# \n", "#\n"))
+            .collect();
+        out.push_str(&format!(
+            "{}{} {}",
+            indent, config.name, example.command_line
+        ));
+        out
+    }
+
+    /// The EXAMPLES entries of `long-help.txt`: the configured examples, or
+    /// examples of the global options and `--version`.
+    pub(crate) fn render_help_examples(config: &CliConfig) -> String {
+        let configured = Self::all_examples(config);
+        if !configured.is_empty() {
+            return configured
+                .into_iter()
+                .map(|example| Self::format_example(config, example, "    "))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+        }
+        let mut examples = Self::global_help_examples(config);
+        examples.push(format!(
+            "    # Show version information\n    {} --version",
+            config.name
+        ));
+        examples.join("\n\n")
+    }
+
+    /// `after_long_help = "..."` listing the examples of `command`, if any.
+    pub(crate) fn render_after_long_help(
+        config: &CliConfig,
+        command: &CommandConfig,
+    ) -> Option<String> {
+        if command.examples.is_empty() {
+            return None;
+        }
+        let examples: Vec<String> = command
+            .examples
+            .iter()
+            .map(|example| Self::format_example(config, example, "  "))
+            .collect();
+        let help = format!("Examples:\n{}", examples.join("\n\n"));
+        Some(format!(
+            "after_long_help = \"{}\"",
+            Self::escape(&help).replace('\n', "\\n")
+        ))
+    }
+
+    /// A call of `name` with `args`, indented by `indent` and laid out the
+    /// way rustfmt would.
+    fn render_call(indent: &str, name: &str, args: &[String]) -> String {
+        let joined = args.join(", ");
+        let one_line = format!("{}{}({});", indent, name, joined);
+        // A lone argument only moves to its own line past the line width
+        if (args.len() == 1 || joined.len() <= CALL_WIDTH) && one_line.len() <= 100 {
+            return one_line + "\n";
+        }
+        let mut out = format!("{}{}(\n", indent, name);
+        for arg in args {
+            out.push_str(&format!("{}    {},\n", indent, arg));
+        }
+        out.push_str(&format!("{});\n", indent));
+        out
+    }
+
+    /// `Man::new` argument of `examples/mangen.rs`; the examples get their
+    /// own section instead of clap_mangen's EXTRA one.
+    pub(crate) fn render_man_page_command(config: &CliConfig) -> String {
+        if Self::all_examples(config).is_empty() {
+            return "command.clone()".to_string();
+        }
+        "command.clone().after_long_help(None::<&str>)".to_string()
+    }
+
+    /// Call appending the EXAMPLES section to each man page.
+    pub(crate) fn render_man_examples(config: &CliConfig) -> String {
+        if Self::all_examples(config).is_empty() {
+            return String::new();
+        }
+        "    page.extend_from_slice(examples_section(command).as_bytes());".to_string()
+    }
+
+    /// `examples_section` of `examples/mangen.rs`, with the examples of the
+    /// CLI on its own page and those of each command on the command's.
+    pub(crate) fn render_man_examples_fn(config: &CliConfig) -> String {
+        if Self::all_examples(config).is_empty() {
+            return String::new();
+        }
+        let pages = std::iter::once((config.name.as_str(), &config.examples)).chain(
+            config
+                .commands
+                .iter()
+                .map(|command| (command.name.as_str(), &command.examples)),
+        );
+        let mut arms = String::new();
+        for (page, examples) in pages.filter(|(_, examples)| !examples.is_empty()) {
+            arms.push_str(&format!("        \"{}\" => {{\n", page));
+            for example in examples {
+                let line = format!("{} {}", config.name, example.command_line);
+                let args = [
+                    "&mut roff".to_string(),
+                    format!("\"{}\"", Self::escape(&line)),
+                    format!(
+                        "\"{}\"",
+                        Self::escape(&example.description).replace('\n', " ")
+                    ),
+                ];
+                arms.push_str(&Self::render_call("            ", "example", &args));
+            }
+            arms.push_str("        }\n");
+        }
+        format!(
+            r#"
+/// EXAMPLES section of the page of `command`, if it has examples.
+fn examples_section(command: &Command) -> String {{
+    let mut roff = Roff::new();
+    roff.control("SH", ["EXAMPLES"]);
+    match command.get_name() {{
+{}        _ => return String::new(),
+    }}
+    roff.to_roff()
+}}
+
+/// An example command line, followed by what it does.
+fn example(roff: &mut Roff, line: &str, description: &str) {{
+    roff.control("TP", [])
+        .text([clap_mangen::roff::bold(line)])
+        .text([roman(description)]);
+}}"#,
+            arms
+        )
+    }
+
+    /// A test module in the parser module checking that every example
+    /// command line parses.
+    pub(crate) fn render_example_tests(config: &CliConfig) -> String {
+        if !config.example_tests || Self::all_examples(config).is_empty() {
+            return String::new();
+        }
+        format!(
+            r#"
+#[cfg(test)]
+mod example_tests {{
+    use super::*;
+    use clap::error::ErrorKind;
+
+    /// Parse an example command line, split into words like a shell would.
+    fn parse(line: &str) {{
+        let mut words = Vec::new();
+        let mut word: Option<String> = None;
+        let mut quote = None;
+        for c in line.chars() {{
+            match (quote, c) {{
+                (None, '\'' | '"') => {{
+                    quote = Some(c);
+                    word.get_or_insert_with(String::new);
+                }}
+                (Some(open), c) if c == open => quote = None,
+                (None, c) if c.is_whitespace() => words.extend(word.take()),
+                (_, c) => word.get_or_insert_with(String::new).push(c),
+            }}
+        }}
+        words.extend(word);
+        if let Err(e) = {}::try_parse_from(&words) {{
+            assert_eq!(e.kind(), ErrorKind::DisplayHelp, "{{}}: {{}}", line, e);
+        }}
+    }}
+{}}}"#,
+            Self::parser_path(config).1,
+            Self::render_example_test_fns(config)
+        )
+    }
+
+    /// One `#[test]` per example, numbered within the CLI or its command.
+    fn render_example_test_fns(config: &CliConfig) -> String {
+        let scopes = std::iter::once(("example".to_string(), &config.examples)).chain(
+            config.commands.iter().map(|command| {
+                (
+                    format!("{}_example", command.name.replace('-', "_")),
+                    &command.examples,
+                )
+            }),
+        );
+        let mut tests = String::new();
+        for (prefix, examples) in scopes {
+            for (i, example) in examples.iter().enumerate() {
+                let line = format!("{} {}", config.name, example.command_line);
+                tests.push_str(&format!(
+                    "\n    #[test]\n    fn {}_{}_parses() {{\n{}    }}\n",
+                    prefix,
+                    i + 1,
+                    Self::render_call(
+                        "        ",
+                        "parse",
+                        &[format!("\"{}\"", Self::escape(&line))]
+                    )
+                ));
+            }
+        }
+        tests
+    }
+
+    /// Words of an example command line, the way the generated tests split
+    /// it: whitespace separates words except within single or double quotes.
+    fn split_words(line: &str) -> Result<Vec<String>, String> {
+        let mut words = Vec::new();
+        let mut word: Option<String> = None;
+        let mut quote = None;
+        for c in line.chars() {
+            match (quote, c) {
+                (None, '\'' | '"') => {
+                    quote = Some(c);
+                    word.get_or_insert_with(String::new);
+                }
+                (Some(open), c) if c == open => quote = None,
+                (None, c) if c.is_whitespace() => words.extend(word.take()),
+                (_, c) => word.get_or_insert_with(String::new).push(c),
+            }
+        }
+        if quote.is_some() {
+            return Err(format!("Unterminated quote in example '{}'", line));
+        }
+        words.extend(word);
+        if words.is_empty() {
+            return Err("Example command line cannot be empty".to_string());
+        }
+        Ok(words)
+    }
+
+    pub(crate) fn validate_examples(config: &CliConfig) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        for (i, example) in config.examples.iter().enumerate() {
+            let path = format!("/examples/{}/command_line", i);
+            if let Err(error) = Self::split_words(&example.command_line) {
+                errors.push(ValidationError::new(path, error));
+            }
+        }
+        for (i, command) in config.commands.iter().enumerate() {
+            for (j, example) in command.examples.iter().enumerate() {
+                let path = format!("/commands/{}/examples/{}/command_line", i, j);
+                match Self::split_words(&example.command_line) {
+                    Err(error) => errors.push(ValidationError::new(path, error)),
+                    Ok(words) if words.first() != Some(&command.name) => {
+                        errors.push(ValidationError::new(
+                            path,
+                            format!(
+                                "Example '{}' of command '{}' must start with the command name",
+                                example.command_line, command.name
+                            ),
+                        ))
+                    }
+                    Ok(_) => {}
+                }
+            }
+        }
+        errors
+    }
+}
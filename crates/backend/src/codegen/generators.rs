@@ -1,211 +1,260 @@
-use crate::models::config::{CliConfig, LicenseType};
+use crate::models::config::{CliConfig, SwCliDependency};
+use crate::models::manifest::{BASE_DIR, MANIFEST_FILE, ManifestFile, ProjectManifest};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fs;
 use std::os::unix::fs::PermissionsExt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Permissions for regular generated files.
+pub const FILE_MODE: u32 = 0o644;
+/// Permissions for generated scripts.
+pub const SCRIPT_MODE: u32 = 0o755;
 
 pub struct CodeGenerator;
 
+/// A single file of a generated project, rendered but not yet written.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GeneratedFile {
+    /// Path relative to the project root
+    pub path: String,
+    pub content: String,
+    pub mode: u32,
+}
+
 impl CodeGenerator {
     pub fn generate_files(
         config: &CliConfig,
         output_dir: &str,
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let files = Self::render_project(config, output_dir)?;
+
         eprintln!("[DEBUG] Creating output directory: {}", output_dir);
         fs::create_dir_all(output_dir)?;
-        fs::create_dir_all(format!("{}/src", output_dir))?;
-        fs::create_dir_all(format!("{}/scripts", output_dir))?;
-        fs::create_dir_all(format!("{}/lib", output_dir))?;
+        if config.sw_cli_dependency == SwCliDependency::Submodule {
+            // setup.sh adds the submodule here
+            fs::create_dir_all(format!("{}/lib", output_dir))?;
+        }
+
+        for file in &files {
+            Self::write_file(output_dir, file)?;
+        }
+
+        eprintln!("[DEBUG] All files generated successfully");
+        Ok(())
+    }
 
+    /// Render the project files together with the manifest and base copies
+    /// that record this rendering into `output_dir`.
+    pub fn render_project(
+        config: &CliConfig,
+        output_dir: &str,
+    ) -> Result<Vec<GeneratedFile>, Box<dyn std::error::Error>> {
+        let mut files = Self::render_files(config)?;
+        let metadata = Self::metadata_files(config, &files, output_dir)?;
+        files.extend(metadata);
+        Ok(files)
+    }
+
+    /// The manifest plus a pristine copy of every file in `files` under
+    /// [`BASE_DIR`], used as the merge ancestor by `cli-gen update`.
+    pub fn metadata_files(
+        config: &CliConfig,
+        files: &[GeneratedFile],
+        output_dir: &str,
+    ) -> Result<Vec<GeneratedFile>, Box<dyn std::error::Error>> {
+        let manifest_files: Vec<ManifestFile> = files
+            .iter()
+            .map(|file| ManifestFile {
+                path: file.path.clone(),
+                sha256: format!("{:x}", Sha256::digest(file.content.as_bytes())),
+                mode: file.mode,
+            })
+            .collect();
+        let manifest = ProjectManifest {
+            cli_gen_version: option_env!("BUILD_COMMIT_SHA")
+                .unwrap_or("unknown")
+                .to_string(),
+            generated_at: Self::generated_at(output_dir, &manifest_files),
+            template_version: Self::template_version()?,
+            config: config.clone(),
+            files: manifest_files,
+        };
+
+        let mut metadata = vec![GeneratedFile {
+            path: MANIFEST_FILE.to_string(),
+            content: toml::to_string_pretty(&manifest)?,
+            mode: FILE_MODE,
+        }];
+        metadata.extend(files.iter().map(|file| GeneratedFile {
+            path: format!("{}/{}", BASE_DIR, file.path),
+            content: file.content.clone(),
+            mode: FILE_MODE,
+        }));
+
+        Ok(metadata)
+    }
+
+    /// Timestamp of the manifest: that of the manifest in `output_dir` when it
+    /// records the same `files`, so regenerating an unchanged project leaves
+    /// the manifest unchanged too.
+    fn generated_at(output_dir: &str, files: &[ManifestFile]) -> String {
+        match Self::read_manifest(output_dir) {
+            Ok(previous) if previous.files == files => previous.generated_at,
+            _ => chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Fingerprint of the template pack: a short SHA-256 over every template's
+    /// path and content, so any template change yields a new version.
+    pub fn template_version() -> Result<String, Box<dyn std::error::Error>> {
+        let template_dir = Path::new(Self::find_template_dir()?);
+
+        let mut hasher = Sha256::new();
+        for path in Self::walk_files(template_dir)? {
+            hasher.update(
+                path.strip_prefix(template_dir)?
+                    .to_string_lossy()
+                    .as_bytes(),
+            );
+            hasher.update(fs::read(&path)?);
+        }
+        let digest = format!("{:x}", hasher.finalize());
+        Ok(digest[..12].to_string())
+    }
+
+    /// Every file below `dir`, recursively, in sorted order. `.git` and
+    /// `target` directories are skipped.
+    pub(crate) fn walk_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            for entry in fs::read_dir(&dir)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    if !path.ends_with(".git") && !path.ends_with("target") {
+                        pending.push(path);
+                    }
+                } else {
+                    paths.push(path);
+                }
+            }
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Write `file` below `output_dir`, creating parent directories as needed.
+    pub fn write_file(
+        output_dir: &str,
+        file: &GeneratedFile,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let output_path = Path::new(output_dir).join(&file.path);
+        eprintln!("[DEBUG] Writing {}", output_path.display());
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&output_path, &file.content)?;
+        Self::set_mode(&output_path, file.mode)
+    }
+
+    /// Render every file of the project in memory without touching the output directory.
+    pub fn render_files(
+        config: &CliConfig,
+    ) -> Result<Vec<GeneratedFile>, Box<dyn std::error::Error>> {
+        let template_dir = Self::find_template_dir()?;
+        let config = &Self::with_builtin_commands(config);
+        let vars = Self::template_vars(config);
+
+        // (template name, output path relative to the project root, mode)
+        let mut outputs = vec![
+            ("COPYRIGHT", "COPYRIGHT".to_string(), FILE_MODE),
+            (".gitignore", ".gitignore".to_string(), FILE_MODE),
+            ("README.md", "README.md".to_string(), FILE_MODE),
+        ];
+        outputs.extend(Self::xtask_outputs(config));
+        if !config.xtask {
+            // Scripts are generated with executable permissions
+            outputs.push(("setup.sh", "scripts/setup.sh".to_string(), SCRIPT_MODE));
+            outputs.push(("build.sh", "scripts/build.sh".to_string(), SCRIPT_MODE));
+        }
+        outputs.extend(Self::layout_outputs(config));
+        outputs.extend(Self::ci_outputs(config));
+        if config.sw_cli_dependency == SwCliDependency::Submodule {
+            outputs.push((".gitmodules", ".gitmodules".to_string(), FILE_MODE));
+        }
+
+        let mut files = Vec::with_capacity(outputs.len());
+        for (template_name, path, mode) in outputs {
+            files.push(GeneratedFile {
+                path,
+                content: Self::render_template(template_dir, template_name, &vars)?,
+                mode,
+            });
+        }
+        files.extend(Self::command_files(config, template_dir, &vars)?);
+        files.extend(Self::vendored_files(config)?);
+
+        Ok(files)
+    }
+
+    fn find_template_dir() -> Result<&'static str, Box<dyn std::error::Error>> {
         // Try multiple paths to find templates (handles different working directories)
-        let template_paths = vec![
+        let template_paths = [
             "templates",                // When running from crates/backend
             "crates/backend/templates", // When running from project root
             "./templates",
         ];
 
         eprintln!("[DEBUG] Looking for templates directory...");
-        let mut template_dir = None;
-        for path in &template_paths {
+        for path in template_paths {
             eprintln!("[DEBUG] Checking path: {}", path);
             if Path::new(path).exists() {
                 eprintln!("[DEBUG] Found templates at: {}", path);
-                template_dir = Some(path);
-                break;
+                return Ok(path);
             }
         }
 
-        let template_dir = template_dir.ok_or("Could not find templates directory")?;
-
-        // Get license string for Cargo.toml
-        let license_str = match &config.license {
-            LicenseType::MIT => "MIT",
-            LicenseType::Apache2 => "Apache-2.0",
-            LicenseType::GPL3 => "GPL-3.0",
-            LicenseType::Custom(s) => s.as_str(),
-        };
-
-        // Read and process all templates
-        Self::generate_from_template(
-            template_dir,
-            "main.rs",
-            &format!("{}/src/main.rs", output_dir),
-            config,
-            license_str,
-        )?;
-
-        Self::generate_from_template(
-            template_dir,
-            "cli.rs",
-            &format!("{}/src/cli.rs", output_dir),
-            config,
-            license_str,
-        )?;
-
-        Self::generate_from_template(
-            template_dir,
-            "lib.rs",
-            &format!("{}/src/lib.rs", output_dir),
-            config,
-            license_str,
-        )?;
-
-        Self::generate_from_template(
-            template_dir,
-            "new_build.rs",
-            &format!("{}/build.rs", output_dir),
-            config,
-            license_str,
-        )?;
-
-        Self::generate_from_template(
-            template_dir,
-            "Cargo.toml",
-            &format!("{}/Cargo.toml", output_dir),
-            config,
-            license_str,
-        )?;
-
-        Self::generate_from_template(
-            template_dir,
-            "COPYRIGHT",
-            &format!("{}/COPYRIGHT", output_dir),
-            config,
-            license_str,
-        )?;
-
-        Self::generate_from_template(
-            template_dir,
-            ".gitmodules",
-            &format!("{}/.gitmodules", output_dir),
-            config,
-            license_str,
-        )?;
-
-        Self::generate_from_template(
-            template_dir,
-            ".gitignore",
-            &format!("{}/.gitignore", output_dir),
-            config,
-            license_str,
-        )?;
-
-        Self::generate_from_template(
-            template_dir,
-            "short-help.txt",
-            &format!("{}/src/short-help.txt", output_dir),
-            config,
-            license_str,
-        )?;
-
-        Self::generate_from_template(
-            template_dir,
-            "long-help.txt",
-            &format!("{}/src/long-help.txt", output_dir),
-            config,
-            license_str,
-        )?;
-
-        // Generate scripts with executable permissions
-        Self::generate_from_template(
-            template_dir,
-            "setup.sh",
-            &format!("{}/scripts/setup.sh", output_dir),
-            config,
-            license_str,
-        )?;
-        Self::set_executable(&format!("{}/scripts/setup.sh", output_dir))?;
-
-        Self::generate_from_template(
-            template_dir,
-            "build.sh",
-            &format!("{}/scripts/build.sh", output_dir),
-            config,
-            license_str,
-        )?;
-        Self::set_executable(&format!("{}/scripts/build.sh", output_dir))?;
-
-        eprintln!("[DEBUG] All files generated successfully");
-        Ok(())
+        Err("Could not find templates directory".into())
     }
 
-    fn generate_from_template(
+    pub(crate) fn render_template(
         template_dir: &str,
         template_name: &str,
-        output_path: &str,
-        config: &CliConfig,
-        license_str: &str,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        eprintln!("[DEBUG] Generating {} from template", output_path);
+        vars: &[(&'static str, String)],
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        eprintln!("[DEBUG] Rendering {} template", template_name);
         let template_path = format!("{}/{}", template_dir, template_name);
         let template_content = fs::read_to_string(&template_path)
             .map_err(|e| format!("Failed to read {}: {}", template_path, e))?;
 
-        // Convert package name to valid crate name (replace - with _)
-        let crate_name = config.name.replace("-", "_");
-
-        let content = template_content
-            .replace("{{ name }}", &config.name)
-            .replace("{{ crate_name }}", &crate_name)
-            .replace("{{ short_description }}", &config.short_description)
-            .replace("{{ long_description }}", &config.long_description)
-            .replace("{{ author }}", &config.author)
-            .replace("{{ copyright }}", &config.copyright)
-            .replace("{{ license }}", license_str)
-            .replace("{{ repository }}", &config.repository)
-            .replace("{{ version }}", &config.version)
-            .replace("{{ sw_cli_url }}", &config.sw_cli_url);
-
-        fs::write(output_path, content)?;
-        Ok(())
+        // A placeholder alone on its line that renders to nothing removes the line
+        let template_content: String = template_content
+            .split_inclusive('\n')
+            .filter(|line| {
+                !vars.iter().any(|(key, value)| {
+                    value.is_empty() && line.trim() == format!("{{{{ {} }}}}", key)
+                })
+            })
+            .collect();
+
+        let content = vars.iter().fold(template_content, |content, (key, value)| {
+            content.replace(&format!("{{{{ {} }}}}", key), value)
+        });
+
+        Ok(content)
     }
 
-    fn set_executable(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    fn set_mode(path: &Path, mode: u32) -> Result<(), Box<dyn std::error::Error>> {
         #[cfg(unix)]
         {
             let metadata = fs::metadata(path)?;
             let mut permissions = metadata.permissions();
-            permissions.set_mode(0o755);
+            permissions.set_mode(mode);
             fs::set_permissions(path, permissions)?;
         }
         Ok(())
     }
-
-    pub fn validate_config(config: &CliConfig) -> Result<(), String> {
-        if config.name.is_empty() {
-            return Err("CLI name cannot be empty".to_string());
-        }
-
-        if config.short_description.is_empty() {
-            return Err("Short description cannot be empty".to_string());
-        }
-
-        if config.long_description.is_empty() {
-            return Err("Long description cannot be empty".to_string());
-        }
-
-        Ok(())
-    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
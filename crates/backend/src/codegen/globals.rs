@@ -0,0 +1,312 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{CliConfig, GlobalOption};
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+/// The parser field a global option adds.
+pub(crate) struct GlobalField {
+    pub name: &'static str,
+    pub ty: &'static str,
+    /// Value of the field in a fresh `CliConfigBuilder`
+    pub default: &'static str,
+    pub short: Option<char>,
+    pub long: &'static str,
+    /// Contents of the `#[arg(...)]` attribute
+    pub attrs: Cow<'static, str>,
+    pub description: &'static str,
+    /// The option as shown by `--help`, e.g. `--color <WHEN>`
+    pub usage: &'static str,
+}
+
+const VERBOSE: GlobalField = GlobalField {
+    name: "verbose",
+    ty: "bool",
+    default: "false",
+    short: Some('v'),
+    long: "verbose",
+    attrs: Cow::Borrowed("short, long"),
+    description: "Enable verbose output",
+    usage: "-v, --verbose",
+};
+
+const DRY_RUN: GlobalField = GlobalField {
+    name: "dry_run",
+    ty: "bool",
+    default: "false",
+    short: Some('n'),
+    long: "dry-run",
+    attrs: Cow::Borrowed("short = 'n', long"),
+    description: "Dry-run mode (show what would be done without doing it)",
+    usage: "-n, --dry-run",
+};
+
+/// `--quiet` before it learns which verbosity option it conflicts with.
+const QUIET: GlobalField = GlobalField {
+    name: "quiet",
+    ty: "bool",
+    default: "false",
+    short: Some('q'),
+    long: "quiet",
+    attrs: Cow::Borrowed("short, long"),
+    description: "Only print errors",
+    usage: "-q, --quiet",
+};
+
+const VERBOSITY: GlobalField = GlobalField {
+    name: "verbosity",
+    ty: "u8",
+    default: "0",
+    short: Some('v'),
+    long: "verbose",
+    attrs: Cow::Borrowed("short = 'v', long = \"verbose\", action = clap::ArgAction::Count"),
+    description: "Increase verbosity (-v, -vv, -vvv)",
+    usage: "-v, --verbose...",
+};
+
+const COLOR: GlobalField = GlobalField {
+    name: "color",
+    ty: "clap::ColorChoice",
+    default: "clap::ColorChoice::Auto",
+    short: None,
+    long: "color",
+    attrs: Cow::Borrowed("long, value_name = \"WHEN\", default_value = \"auto\""),
+    description: "When to use colors (auto, always, never)",
+    usage: "--color <WHEN>",
+};
+
+const CONFIG_FILE: GlobalField = GlobalField {
+    name: "config",
+    ty: "Option<std::path::PathBuf>",
+    default: "None",
+    short: None,
+    long: "config",
+    attrs: Cow::Borrowed("long, value_name = \"FILE\""),
+    description: "Read settings from FILE",
+    usage: "--config <FILE>",
+};
+
+const OUTPUT: GlobalField = GlobalField {
+    name: "output",
+    ty: "OutputFormat",
+    default: "OutputFormat::Text",
+    short: None,
+    long: "output",
+    attrs: Cow::Borrowed("long, value_name = \"FORMAT\", value_enum, default_value_t"),
+    description: "Output format (text, json)",
+    usage: "--output <FORMAT>",
+};
+
+const NO_INPUT: GlobalField = GlobalField {
+    name: "no_input",
+    ty: "bool",
+    default: "false",
+    short: None,
+    long: "no-input",
+    attrs: Cow::Borrowed("long"),
+    description: "Never prompt for input",
+    usage: "--no-input",
+};
+
+/// `use_color` of the parser, for `--color`.
+const USE_COLOR: &str = r#"    /// Whether to color output: `--color`, else `NO_COLOR` and whether
+    /// stdout is a terminal.
+    pub fn use_color(&self) -> bool {
+        match self.color {
+            clap::ColorChoice::Always => true,
+            clap::ColorChoice::Never => false,
+            clap::ColorChoice::Auto => {
+                std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+            }
+        }
+    }"#;
+
+/// `interactive` of the parser, for `--no-input`.
+const INTERACTIVE: &str = r#"    /// Whether the user may be prompted: not disabled by `--no-input`
+    /// and stdin is a terminal.
+    pub fn interactive(&self) -> bool {
+        !self.no_input && std::io::stdin().is_terminal()
+    }"#;
+
+/// The value enum of `--output`.
+const OUTPUT_FORMAT: &str = r#"
+/// Format of the command output.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// JSON, for scripts
+    Json,
+}
+"#;
+
+impl CodeGenerator {
+    pub(crate) fn global_field(config: &CliConfig, option: GlobalOption) -> GlobalField {
+        match option {
+            GlobalOption::Verbose => VERBOSE,
+            GlobalOption::DryRun => DRY_RUN,
+            GlobalOption::Quiet => {
+                let loud = [GlobalOption::Verbose, GlobalOption::Verbosity]
+                    .into_iter()
+                    .find(|loud| config.global_options.contains(loud));
+                match loud {
+                    Some(loud) => GlobalField {
+                        attrs: format!(
+                            "short, long, conflicts_with = \"{}\"",
+                            Self::global_field(config, loud).name
+                        )
+                        .into(),
+                        ..QUIET
+                    },
+                    None => QUIET,
+                }
+            }
+            GlobalOption::Verbosity => VERBOSITY,
+            GlobalOption::Color => COLOR,
+            GlobalOption::ConfigFile => CONFIG_FILE,
+            GlobalOption::Output => OUTPUT,
+            GlobalOption::NoInput => NO_INPUT,
+        }
+    }
+
+    /// Fields of the selected global options, plus `--log-file` and `--set`
+    /// if enabled.
+    pub(crate) fn global_fields(config: &CliConfig) -> Vec<GlobalField> {
+        config
+            .global_options
+            .iter()
+            .map(|option| Self::global_field(config, *option))
+            .chain(Self::log_file_field(config))
+            .chain(Self::settings_field(config))
+            .collect()
+    }
+
+    /// Clap fields of the selected global options, ahead of the configured
+    /// top-level arguments.
+    pub(crate) fn render_global_args(config: &CliConfig) -> String {
+        Self::global_fields(config)
+            .iter()
+            .map(|field| {
+                format!(
+                    "    /// {}\n    #[arg({})]\n    pub {}: {},\n",
+                    field.description, field.attrs, field.name, field.ty
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim_end()
+            .to_string()
+    }
+
+    /// Types and methods backing the selected global options, rendered after
+    /// the parser struct.
+    pub(crate) fn render_global_support(config: &CliConfig) -> String {
+        let options = &config.global_options;
+        let mut methods = Vec::new();
+        if options.contains(&GlobalOption::Color) {
+            methods.push(USE_COLOR);
+        }
+        if options.contains(&GlobalOption::NoInput) {
+            methods.push(INTERACTIVE);
+        }
+
+        let mut out = String::new();
+        if !methods.is_empty() {
+            out.push_str(&format!(
+                "\nimpl {} {{\n{}\n}}\n",
+                Self::parser_path(config).1,
+                methods.join("\n\n")
+            ));
+        }
+        if options.contains(&GlobalOption::Output) {
+            out.push_str(OUTPUT_FORMAT);
+        }
+        out.trim_end().to_string()
+    }
+
+    /// `use` of `IsTerminal` for the terminal checks of the global options.
+    pub(crate) fn render_global_imports(config: &CliConfig) -> String {
+        if config.global_options.contains(&GlobalOption::Color)
+            || config.global_options.contains(&GlobalOption::NoInput)
+        {
+            "use std::io::IsTerminal;".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Debug trace at the start of each command handler: a `debug!` log with
+    /// a logging backend, else printed when the verbose option is set on
+    /// `receiver`.
+    pub(crate) fn render_verbose_trace(config: &CliConfig, receiver: &str, name: &str) -> String {
+        if let Some(facade) = Self::log_facade(config) {
+            return format!(
+                "        {}::debug!(\"Running the {} command with {{:?}}\", self);\n",
+                facade, name
+            );
+        }
+        let condition = if config.global_options.contains(&GlobalOption::Verbose) {
+            format!("{}.verbose", receiver)
+        } else if config.global_options.contains(&GlobalOption::Verbosity) {
+            format!("{}.verbosity > 0", receiver)
+        } else {
+            return String::new();
+        };
+        format!(
+            "        if {} {{\n            eprintln!(\"Running the {} command with {{:?}}\", self);\n        }}\n",
+            condition, name
+        )
+    }
+
+    /// Name of the parser parameter of command handlers, underscored when
+    /// the handler has no verbose trace using it.
+    pub(crate) fn handler_param(config: &CliConfig, receiver: &str) -> String {
+        let trace = Self::render_verbose_trace(config, receiver, "");
+        if !trace.contains(&format!("{}.", receiver)) {
+            format!("_{}", receiver)
+        } else {
+            receiver.to_string()
+        }
+    }
+
+    /// The EXAMPLES entries of `long-help.txt` for the global options, used
+    /// when no examples are configured.
+    pub(crate) fn global_help_examples(config: &CliConfig) -> Vec<String> {
+        let mut examples = Vec::new();
+        for option in &config.global_options {
+            let (comment, args) = match option {
+                GlobalOption::Verbose => ("Run with verbose output", "--verbose"),
+                GlobalOption::DryRun => ("Dry-run to see what would happen", "--dry-run"),
+                GlobalOption::Quiet => ("Only report errors", "--quiet"),
+                GlobalOption::Verbosity => ("Run with more detailed output", "-vv"),
+                GlobalOption::ConfigFile => ("Use settings from a file", "--config settings.toml"),
+                GlobalOption::Output => ("Print JSON for scripts", "--output json"),
+                GlobalOption::NoInput => ("Run without prompting, e.g. in CI", "--no-input"),
+                GlobalOption::Color => continue,
+            };
+            examples.push(format!("    # {}\n    {} {}", comment, config.name, args));
+        }
+        examples
+    }
+
+    pub(crate) fn validate_global_options(config: &CliConfig) -> Vec<ValidationError> {
+        let mut seen = HashSet::new();
+        let mut errors = Vec::new();
+        for (i, option) in config.global_options.iter().enumerate() {
+            if !seen.insert(option) {
+                errors.push(ValidationError::new(
+                    format!("/global_options/{}", i),
+                    format!("Duplicate global option {:?}", option),
+                ));
+            }
+        }
+        if seen.contains(&GlobalOption::Verbose) && seen.contains(&GlobalOption::Verbosity) {
+            errors.push(ValidationError::new(
+                "/global_options",
+                "The Verbose and Verbosity global options both use -v/--verbose; pick one",
+            ));
+        }
+        errors
+    }
+}
@@ -0,0 +1,240 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{ArgConfig, ArgGroupConfig};
+use std::collections::HashSet;
+
+impl CodeGenerator {
+    /// `conflicts_with`, `requires` and `required_unless_present` attributes
+    /// of `arg`; clap collects each repeated attribute into a list.
+    pub(crate) fn relation_attrs(arg: &ArgConfig) -> Vec<String> {
+        let relations = [
+            ("conflicts_with", &arg.conflicts_with),
+            ("requires", &arg.requires),
+            ("required_unless_present", &arg.required_unless_present),
+        ];
+        relations
+            .into_iter()
+            .flat_map(|(attr, names)| {
+                names
+                    .iter()
+                    .map(move |name| format!("{} = \"{}\"", attr, name))
+            })
+            .collect()
+    }
+
+    /// One `#[command(group(...))]` attribute per group, indented by `indent`.
+    pub(crate) fn render_arg_groups(groups: &[ArgGroupConfig], indent: &str) -> String {
+        let mut out = String::new();
+        for group in groups {
+            let args: Vec<String> = group
+                .args
+                .iter()
+                .map(|arg| format!("\"{}\"", arg))
+                .collect();
+            let mut builder = format!(
+                "clap::ArgGroup::new(\"{}\").args([{}])",
+                group.name,
+                args.join(", ")
+            );
+            if group.required {
+                builder.push_str(".required(true)");
+            }
+            if group.multiple {
+                builder.push_str(".multiple(true)");
+            }
+            out.push_str(&format!("{}#[command(group({}))]\n", indent, builder));
+        }
+        out
+    }
+
+    /// Check that the relations and groups of `args` only refer to arguments
+    /// of the same scope (or to `globals`) and can all be satisfied. `path`
+    /// is the JSON pointer of the CLI or command owning them.
+    pub(crate) fn validate_relations(
+        args: &[ArgConfig],
+        groups: &[ArgGroupConfig],
+        path: &str,
+        scope: &str,
+        globals: &[&str],
+    ) -> Vec<ValidationError> {
+        let arg_names: HashSet<&str> = args.iter().map(|arg| arg.name.as_str()).collect();
+        let mut group_names = HashSet::new();
+        let mut errors = Vec::new();
+        for (i, group) in groups.iter().enumerate() {
+            let group_path = format!("{}/groups/{}", path, i);
+            if arg_names.contains(group.name.as_str())
+                || globals.contains(&group.name.as_str())
+                || !group_names.insert(group.name.as_str())
+            {
+                errors.push(ValidationError::new(
+                    format!("{}/name", group_path),
+                    format!("Duplicate group '{}' in {}", group.name, scope),
+                ));
+            }
+            errors.extend(Self::validate_group(group, &arg_names, &group_path, scope));
+        }
+
+        let known = |name: &str| {
+            arg_names.contains(name) || group_names.contains(name) || globals.contains(&name)
+        };
+        for (i, arg) in args.iter().enumerate() {
+            let arg_path = format!("{}/args/{}", path, i);
+            errors.extend(Self::validate_arg_relations(
+                arg, args, &arg_path, scope, known,
+            ));
+        }
+        errors
+    }
+
+    fn validate_group(
+        group: &ArgGroupConfig,
+        arg_names: &HashSet<&str>,
+        path: &str,
+        scope: &str,
+    ) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if !Self::is_snake_case(&group.name) {
+            errors.push(ValidationError::new(
+                format!("{}/name", path),
+                format!(
+                    "Group name '{}' in {} must be a snake_case identifier",
+                    group.name, scope
+                ),
+            ));
+        }
+        if group.args.is_empty() {
+            errors.push(ValidationError::new(
+                format!("{}/args", path),
+                format!("Group '{}' in {} has no arguments", group.name, scope),
+            ));
+        }
+        let mut members = HashSet::new();
+        for (i, name) in group.args.iter().enumerate() {
+            let member_path = format!("{}/args/{}", path, i);
+            if !arg_names.contains(name.as_str()) {
+                errors.push(ValidationError::new(
+                    member_path,
+                    format!(
+                        "Group '{}' in {} refers to unknown argument '{}'",
+                        group.name, scope, name
+                    ),
+                ));
+            } else if !members.insert(name.as_str()) {
+                errors.push(ValidationError::new(
+                    member_path,
+                    format!(
+                        "Argument '{}' is listed twice in group '{}' in {}",
+                        name, group.name, scope
+                    ),
+                ));
+            }
+        }
+        errors
+    }
+
+    fn validate_arg_relations(
+        arg: &ArgConfig,
+        args: &[ArgConfig],
+        path: &str,
+        scope: &str,
+        known: impl Fn(&str) -> bool,
+    ) -> Vec<ValidationError> {
+        let mut errors = Self::validate_references(arg, path, scope, known);
+        if arg.required && !arg.required_unless_present.is_empty() {
+            errors.push(ValidationError::new(
+                format!("{}/required", path),
+                format!(
+                    "Argument '{}' in {} cannot be both required and required unless another is present",
+                    arg.name, scope
+                ),
+            ));
+        }
+        for (i, name) in arg.requires.iter().enumerate() {
+            if arg.conflicts_with.contains(name) {
+                errors.push(ValidationError::new(
+                    format!("{}/requires/{}", path, i),
+                    format!(
+                        "Argument '{}' in {} both requires and conflicts with '{}'",
+                        arg.name, scope, name
+                    ),
+                ));
+            }
+        }
+        errors.extend(Self::validate_conflicts(arg, args, path, scope));
+        errors
+    }
+
+    /// Check that the relations of `arg` name other, `known` arguments or
+    /// groups.
+    fn validate_references(
+        arg: &ArgConfig,
+        path: &str,
+        scope: &str,
+        known: impl Fn(&str) -> bool,
+    ) -> Vec<ValidationError> {
+        let relations = [
+            ("conflicts_with", &arg.conflicts_with),
+            ("requires", &arg.requires),
+            ("required_unless_present", &arg.required_unless_present),
+        ];
+        let mut errors = Vec::new();
+        for (key, names) in relations {
+            for (i, name) in names.iter().enumerate() {
+                let message = if *name == arg.name {
+                    format!("Argument '{}' in {} refers to itself", arg.name, scope)
+                } else if !known(name) {
+                    format!(
+                        "Argument '{}' in {} refers to unknown argument or group '{}'",
+                        arg.name, scope, name
+                    )
+                } else {
+                    continue;
+                };
+                errors.push(ValidationError::new(
+                    format!("{}/{}/{}", path, key, i),
+                    message,
+                ));
+            }
+        }
+        errors
+    }
+
+    /// Check that `arg` neither requires nor is required with an argument it
+    /// conflicts with.
+    fn validate_conflicts(
+        arg: &ArgConfig,
+        args: &[ArgConfig],
+        path: &str,
+        scope: &str,
+    ) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        for other in args {
+            let conflicting = arg.conflicts_with.contains(&other.name)
+                || other.conflicts_with.contains(&arg.name);
+            if !conflicting {
+                continue;
+            }
+            // A direct contradiction is reported above
+            if arg.requires.contains(&other.name) && !arg.conflicts_with.contains(&other.name) {
+                errors.push(ValidationError::new(
+                    format!("{}/requires", path),
+                    format!(
+                        "Argument '{}' in {} requires '{}', which conflicts with it",
+                        arg.name, scope, other.name
+                    ),
+                ));
+            }
+            // Reported once per pair
+            if arg.required && other.required && arg.name < other.name {
+                errors.push(ValidationError::new(
+                    format!("{}/required", path),
+                    format!(
+                        "Required arguments '{}' and '{}' in {} conflict",
+                        arg.name, other.name, scope
+                    ),
+                ));
+            }
+        }
+        errors
+    }
+}
@@ -0,0 +1,356 @@
+use crate::codegen::commands::RUST_KEYWORDS;
+use crate::codegen::generators::{CodeGenerator, FILE_MODE, GeneratedFile};
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{
+    Architecture, CliConfig, ErrorHandling, Layout, Logging, SwCliDependency,
+};
+
+impl CodeGenerator {
+    /// Templates rendering the Rust sources and manifests of the selected
+    /// layout, as (template name, output path, mode).
+    pub(crate) fn layout_outputs(config: &CliConfig) -> Vec<(&'static str, String, u32)> {
+        let bin_dir = Self::bin_dir(config);
+        let lib_src = match config.layout {
+            Layout::Workspace => format!("crates/{}-core/src", config.name),
+            _ => "src".to_string(),
+        };
+
+        let mut outputs = Self::manifest_outputs(config, &bin_dir);
+        outputs.extend(Self::entry_outputs(config, &bin_dir, &lib_src));
+        outputs.extend(Self::module_outputs(config, &lib_src));
+
+        // build.rs and the standalone version module belong to the binary,
+        // whose build script provides the build metadata
+        let build_template = match config.sw_cli_dependency {
+            SwCliDependency::Standalone => "standalone_build.rs",
+            _ => "new_build.rs",
+        };
+        outputs.push((build_template, format!("{}build.rs", bin_dir), FILE_MODE));
+        outputs.extend(Self::error_outputs(config, &lib_src));
+        outputs.extend(Self::settings_outputs(config, &lib_src));
+        outputs.extend(Self::logging_outputs(config));
+        if config.sw_cli_dependency == SwCliDependency::Standalone {
+            outputs.push((
+                "version.rs",
+                format!("{}src/version.rs", bin_dir),
+                FILE_MODE,
+            ));
+        }
+
+        outputs
+    }
+
+    /// `Cargo.toml` of the package, or of the workspace and its members.
+    fn manifest_outputs(config: &CliConfig, bin_dir: &str) -> Vec<(&'static str, String, u32)> {
+        if config.layout != Layout::Workspace {
+            return vec![("Cargo.toml", "Cargo.toml".to_string(), FILE_MODE)];
+        }
+        vec![
+            ("workspace_Cargo.toml", "Cargo.toml".to_string(), FILE_MODE),
+            (
+                "bin_Cargo.toml",
+                format!("{}Cargo.toml", bin_dir),
+                FILE_MODE,
+            ),
+            (
+                "core_Cargo.toml",
+                format!("crates/{}-core/Cargo.toml", config.name),
+                FILE_MODE,
+            ),
+        ]
+    }
+
+    /// The library root, help texts, `main.rs` and the tests and examples
+    /// running the binary.
+    fn entry_outputs(
+        config: &CliConfig,
+        bin_dir: &str,
+        lib_src: &str,
+    ) -> Vec<(&'static str, String, u32)> {
+        let mut outputs = Vec::new();
+        if config.layout != Layout::SingleFile {
+            outputs.push(("lib.rs", format!("{}/lib.rs", lib_src), FILE_MODE));
+        }
+        outputs.push((
+            "short-help.txt",
+            format!("{}/short-help.txt", lib_src),
+            FILE_MODE,
+        ));
+        outputs.push((
+            "long-help.txt",
+            format!("{}/long-help.txt", lib_src),
+            FILE_MODE,
+        ));
+
+        let main_template = match (config.layout, config.architecture) {
+            (Layout::SingleFile, _) => "single_main.rs",
+            (Layout::CommandsDir, _) => "commands_main.rs",
+            (_, Architecture::Simple) => "main.rs",
+            (_, Architecture::Dispatch) => "dispatch_main.rs",
+        };
+        outputs.push((main_template, format!("{}src/main.rs", bin_dir), FILE_MODE));
+        outputs.push((
+            "cli_tests.rs",
+            format!("{}tests/cli.rs", bin_dir),
+            FILE_MODE,
+        ));
+        if config.man_pages {
+            outputs.push((
+                "mangen.rs",
+                format!("{}examples/mangen.rs", Self::man_crate_dir(config)),
+                FILE_MODE,
+            ));
+        }
+        if config.snapshot_tests {
+            outputs.push((
+                "help_tests.rs",
+                format!("{}tests/help.rs", bin_dir),
+                FILE_MODE,
+            ));
+        }
+        outputs
+    }
+
+    /// Library modules holding the parser and, in the dispatch architecture,
+    /// the handlers.
+    fn module_outputs(config: &CliConfig, lib_src: &str) -> Vec<(&'static str, String, u32)> {
+        match (config.layout, config.architecture) {
+            (Layout::SingleFile, _) => Vec::new(),
+            (Layout::CommandsDir, _) => vec![
+                ("cli.rs", format!("{}/cli.rs", lib_src), FILE_MODE),
+                (
+                    "commands_mod.rs",
+                    format!("{}/commands/mod.rs", lib_src),
+                    FILE_MODE,
+                ),
+            ],
+            (_, Architecture::Simple) => {
+                vec![("cli.rs", format!("{}/cli.rs", lib_src), FILE_MODE)]
+            }
+            (_, Architecture::Dispatch) => vec![
+                ("config.rs", format!("{}/config.rs", lib_src), FILE_MODE),
+                ("dispatch.rs", format!("{}/dispatch.rs", lib_src), FILE_MODE),
+            ],
+        }
+    }
+
+    /// One module per command under `src/commands/` for the commands-dir layout.
+    pub(crate) fn command_files(
+        config: &CliConfig,
+        template_dir: &str,
+        vars: &[(&'static str, String)],
+    ) -> Result<Vec<GeneratedFile>, Box<dyn std::error::Error>> {
+        if config.layout != Layout::CommandsDir {
+            return Ok(Vec::new());
+        }
+
+        let mut files = Vec::with_capacity(config.commands.len());
+        for command in &config.commands {
+            let mut command_vars = vars.to_vec();
+            command_vars.push(("command_name", command.name.clone()));
+            command_vars.push((
+                "command_type",
+                format!("{}Command", Self::variant_name(&command.name)),
+            ));
+            command_vars.push((
+                "verbose_trace",
+                Self::render_verbose_trace(config, "cli", &command.name),
+            ));
+            command_vars.push(("cli_param", Self::handler_param(config, "cli")));
+            command_vars.push((
+                "command_struct",
+                (Self::render_command_struct(config, command)
+                    + &Self::render_command_value_enums(command))
+                    .trim_end()
+                    .to_string(),
+            ));
+            let template = if Self::is_completions_command(config, command) {
+                "completions_command.rs"
+            } else if Self::is_settings_command(config, command) {
+                "settings_command.rs"
+            } else {
+                "command.rs"
+            };
+            files.push(GeneratedFile {
+                path: format!("src/commands/{}.rs", Self::module_name(&command.name)),
+                content: Self::render_template(template_dir, template, &command_vars)?,
+                mode: FILE_MODE,
+            });
+        }
+
+        Ok(files)
+    }
+
+    /// Directory of the binary crate relative to the project root, with a
+    /// trailing slash unless it is the root itself.
+    pub(crate) fn bin_dir(config: &CliConfig) -> String {
+        match config.layout {
+            Layout::Workspace => format!("crates/{}/", config.name),
+            _ => String::new(),
+        }
+    }
+
+    /// Path from the binary crate back to the project root, for files such
+    /// as `COPYRIGHT` and `.git/HEAD` that stay at the top.
+    pub(crate) fn project_root(config: &CliConfig) -> String {
+        match config.layout {
+            Layout::Workspace => "../../".to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// Name of the library crate the binary imports its parser from.
+    pub(crate) fn lib_crate(config: &CliConfig) -> String {
+        let crate_name = config.name.replace("-", "_");
+        match config.layout {
+            Layout::Workspace => format!("{}_core", crate_name),
+            _ => crate_name,
+        }
+    }
+
+    /// Module declarations at the top of `main.rs`: the error and settings
+    /// modules of the single-file layout, the logging setup, and the standalone version
+    /// module in layouts where it lives in the binary rather than in `lib.rs`.
+    pub(crate) fn render_main_modules(config: &CliConfig) -> String {
+        let mut modules = Vec::new();
+        let mut imports = Vec::new();
+        if config.error_handling != ErrorHandling::None && config.layout == Layout::SingleFile {
+            modules.push("mod error;");
+            imports.push("use error::Result;");
+        }
+        if config.logging != Logging::None {
+            modules.push("mod logging;");
+        }
+        if config.settings && config.layout == Layout::SingleFile {
+            modules.push("mod settings;");
+            imports.push("use settings::Settings;");
+        }
+        if config.sw_cli_dependency == SwCliDependency::Standalone
+            && matches!(config.layout, Layout::SingleFile | Layout::Workspace)
+        {
+            modules.push("mod version;");
+            imports.push("use version::check_version_flag;");
+        }
+
+        let mut out: String = modules
+            .iter()
+            .map(|module| format!("\n{}", module))
+            .collect();
+        if !imports.is_empty() {
+            out.push_str(&format!("\n\n{}", imports.join("\n")));
+        }
+        out
+    }
+
+    /// Workspace dependencies used by the member crates of the workspace layout.
+    pub(crate) fn render_member_dependencies(config: &CliConfig) -> String {
+        let mut dependencies = Vec::new();
+        if config.completions {
+            dependencies.push("clap_complete.workspace = true");
+        }
+        if let Some(facade) = Self::log_facade(config) {
+            dependencies.push(match facade {
+                "tracing" => "tracing.workspace = true",
+                _ => "log.workspace = true",
+            });
+        }
+        if config.sw_cli_dependency != SwCliDependency::Standalone {
+            dependencies.push("sw-cli.workspace = true");
+        }
+        dependencies.join("\n")
+    }
+
+    pub(crate) fn render_member_build_dependencies(config: &CliConfig) -> String {
+        match config.sw_cli_dependency {
+            SwCliDependency::Standalone => String::new(),
+            _ => "\n[build-dependencies]\nsw-cli.workspace = true".to_string(),
+        }
+    }
+
+    /// `pub mod` declarations of `src/commands/mod.rs`, sorted as rustfmt does.
+    pub(crate) fn render_command_modules(config: &CliConfig) -> String {
+        let mut modules: Vec<String> = config
+            .commands
+            .iter()
+            .map(|command| format!("pub mod {};", Self::module_name(&command.name)))
+            .collect();
+        modules.sort();
+        modules.join("\n")
+    }
+
+    /// Variants of the `Commands` enum in `src/commands/mod.rs`.
+    pub(crate) fn render_command_variants(config: &CliConfig) -> String {
+        config
+            .commands
+            .iter()
+            .map(|command| {
+                let payload = format!(
+                    "{}::{}Command",
+                    Self::module_name(&command.name),
+                    Self::variant_name(&command.name)
+                );
+                Self::render_command_variant(config, command, Some(&payload))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim_end()
+            .to_string()
+    }
+
+    /// Match arms of `Commands::run`, one per command.
+    pub(crate) fn render_command_runs(config: &CliConfig) -> String {
+        config
+            .commands
+            .iter()
+            .map(|command| {
+                format!(
+                    "            Commands::{}(command) => command.run(cli),",
+                    Self::variant_name(&command.name)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// `clear-all` -> `clear_all`
+    fn module_name(name: &str) -> String {
+        name.replace('-', "_")
+    }
+
+    pub(crate) fn validate_layout(config: &CliConfig) -> Vec<ValidationError> {
+        match config.layout {
+            Layout::SingleFile | Layout::CommandsDir
+                if config.architecture == Architecture::Dispatch =>
+            {
+                vec![ValidationError::new(
+                    "/layout",
+                    "The dispatch architecture needs the split or workspace layout",
+                )]
+            }
+            Layout::CommandsDir => {
+                if config.commands.is_empty() {
+                    return vec![ValidationError::new(
+                        "/layout",
+                        "The commands-dir layout needs at least one command",
+                    )];
+                }
+                config
+                    .commands
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, command)| {
+                        RUST_KEYWORDS.contains(&Self::module_name(&command.name).as_str())
+                    })
+                    .map(|(i, command)| ValidationError::new(
+                        format!("/commands/{}/name", i),
+                        format!(
+                            "Command name '{}' cannot be used as a module name in the commands-dir layout",
+                            command.name
+                        ),
+                    ))
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::codegen::generators::{CodeGenerator, FILE_MODE};
+use crate::codegen::globals::GlobalField;
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{Architecture, CliConfig, GlobalOption, Logging};
+
+impl CodeGenerator {
+    /// `src/logging.rs` of the binary crate for the selected backend, as
+    /// (template name, output path, mode).
+    pub(crate) fn logging_outputs(config: &CliConfig) -> Vec<(&'static str, String, u32)> {
+        let template = match config.logging {
+            Logging::None => return Vec::new(),
+            Logging::Tracing => "logging_tracing.rs",
+            Logging::EnvLogger => "logging_env_logger.rs",
+        };
+        vec![(
+            template,
+            format!("{}src/logging.rs", Self::bin_dir(config)),
+            FILE_MODE,
+        )]
+    }
+
+    /// The `--log-file` option, rendered with the global options.
+    pub(crate) fn log_file_field(config: &CliConfig) -> Option<GlobalField> {
+        config.log_file.then(|| GlobalField {
+            name: "log_file",
+            ty: "Option<std::path::PathBuf>",
+            default: "None",
+            short: None,
+            long: "log-file",
+            attrs: "long, value_name = \"FILE\"".into(),
+            description: "Append logs to FILE instead of printing them to stderr",
+            usage: "--log-file <FILE>",
+        })
+    }
+
+    /// Crate providing the logging macros to the generated code.
+    pub(crate) fn log_facade(config: &CliConfig) -> Option<&'static str> {
+        match config.logging {
+            Logging::None => None,
+            Logging::Tracing => Some("tracing"),
+            Logging::EnvLogger => Some("log"),
+        }
+    }
+
+    /// Dependencies of the logging facade and of the backend installing it.
+    pub(crate) fn render_logging_dependencies(config: &CliConfig) -> Vec<String> {
+        match config.logging {
+            Logging::None => Vec::new(),
+            Logging::Tracing => {
+                let features = if config.log_json {
+                    "[\"env-filter\", \"json\"]"
+                } else {
+                    "[\"env-filter\"]"
+                };
+                vec![
+                    "tracing = \"0.1\"".to_string(),
+                    format!(
+                        "tracing-subscriber = {{ version = \"0.3\", features = {} }}",
+                        features
+                    ),
+                ]
+            }
+            Logging::EnvLogger => vec![
+                "env_logger = \"0.11\"".to_string(),
+                "log = \"0.4\"".to_string(),
+            ],
+        }
+    }
+
+    /// Dependencies only the binary crate of the workspace layout needs.
+    pub(crate) fn render_bin_dependencies(config: &CliConfig) -> String {
+        match config.logging {
+            Logging::None => String::new(),
+            Logging::Tracing => "tracing-subscriber.workspace = true".to_string(),
+            Logging::EnvLogger => "env_logger.workspace = true".to_string(),
+        }
+    }
+
+    pub(crate) fn render_log_imports(config: &CliConfig) -> String {
+        match (config.log_file, config.logging) {
+            (false, _) => String::new(),
+            (true, Logging::Tracing) => {
+                "use std::fs::OpenOptions;\nuse std::path::Path;\nuse std::sync::Mutex;".to_string()
+            }
+            (true, _) => "use std::fs::OpenOptions;\nuse std::path::Path;".to_string(),
+        }
+    }
+
+    pub(crate) fn render_log_file_param(config: &CliConfig) -> String {
+        if config.log_file {
+            ", log_file: Option<&Path>".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    pub(crate) fn render_log_json(config: &CliConfig) -> String {
+        if config.log_json {
+            "\n        .json()".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Installation of the subscriber or logger, writing to the log file
+    /// when one is given.
+    pub(crate) fn render_log_init(config: &CliConfig) -> String {
+        match (config.logging, config.log_file) {
+            (Logging::Tracing, false) => {
+                "    subscriber.try_init().map_err(|e| e as Box<dyn Error>)".to_string()
+            }
+            (Logging::Tracing, true) => r#"    match log_file {
+        Some(path) => {
+            let file = OpenOptions::new().create(true).append(true).open(path)?;
+            subscriber
+                .with_ansi(false)
+                .with_writer(Mutex::new(file))
+                .try_init()
+        }
+        None => subscriber.try_init(),
+    }
+    .map_err(|e| e as Box<dyn Error>)"#
+                .to_string(),
+            (Logging::EnvLogger, true) => r#"    if let Some(path) = log_file {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        builder.target(env_logger::Target::Pipe(Box::new(file)));
+    }"#
+            .to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// `logging::init(...)` call in `main`, right after parsing.
+    pub(crate) fn render_main_logging(config: &CliConfig) -> String {
+        if config.logging == Logging::None {
+            return String::new();
+        }
+
+        let receiver = match config.architecture {
+            Architecture::Simple => "cli",
+            Architecture::Dispatch => "config",
+        };
+        let options = &config.global_options;
+        let mut args = vec![
+            if options.contains(&GlobalOption::Quiet) {
+                format!("{}.quiet", receiver)
+            } else {
+                "false".to_string()
+            },
+            if options.contains(&GlobalOption::Verbosity) {
+                format!("{}.verbosity", receiver)
+            } else if options.contains(&GlobalOption::Verbose) {
+                format!("u8::from({}.verbose)", receiver)
+            } else {
+                "0".to_string()
+            },
+        ];
+        if config.log_file {
+            args.push(format!("{}.log_file.as_deref()", receiver));
+        }
+
+        // Laid out the way rustfmt does once the arguments get too wide
+        let one_line = args.join(", ");
+        let call = if one_line.len() <= 60 {
+            format!("logging::init({})", one_line)
+        } else {
+            format!(
+                "logging::init(\n        {},\n    )",
+                args.join(",\n        ")
+            )
+        };
+        format!(
+            "\n    if let Err(e) = {} {{\n        eprintln!(\"Error: {{}}\", e);\n        {}\n    }}",
+            call,
+            Self::main_failure(config)
+        )
+    }
+
+    pub(crate) fn validate_logging(config: &CliConfig) -> Vec<ValidationError> {
+        if config.logging == Logging::None && (config.log_json || config.log_file) {
+            return vec![ValidationError::new(
+                "/logging",
+                "JSON logs and log files need a logging backend",
+            )];
+        }
+        if config.log_json && config.logging != Logging::Tracing {
+            return vec![ValidationError::new(
+                "/log_json",
+                "JSON logs need the tracing backend",
+            )];
+        }
+        Vec::new()
+    }
+}
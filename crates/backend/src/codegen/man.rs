@@ -0,0 +1,58 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{Architecture, CliConfig, Layout};
+
+impl CodeGenerator {
+    /// Module and type of the clap parser, as imported from the library.
+    pub(crate) fn parser_path(config: &CliConfig) -> (&'static str, &'static str) {
+        match config.architecture {
+            Architecture::Simple => ("cli", "Cli"),
+            Architecture::Dispatch => ("config", "CliConfig"),
+        }
+    }
+
+    /// Crate directory holding `examples/mangen.rs`, next to the parser.
+    pub(crate) fn man_crate_dir(config: &CliConfig) -> String {
+        match config.layout {
+            Layout::Workspace => format!("crates/{}-core/", config.name),
+            _ => String::new(),
+        }
+    }
+
+    /// Package holding `examples/mangen.rs`.
+    pub(crate) fn man_package(config: &CliConfig) -> String {
+        match config.layout {
+            Layout::Workspace => format!("{}-core", config.name),
+            _ => config.name.clone(),
+        }
+    }
+
+    /// `[dev-dependencies]` entry of the package holding the parser.
+    pub(crate) fn render_man_dev_dependencies(config: &CliConfig) -> String {
+        if config.man_pages && config.layout != Layout::Workspace {
+            "clap_mangen = \"0.2\"".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// `[dev-dependencies]` section of the core crate of the workspace layout.
+    pub(crate) fn render_core_dev_dependencies(config: &CliConfig) -> String {
+        if config.man_pages && config.layout == Layout::Workspace {
+            "\n[dev-dependencies]\nclap_mangen = \"0.2\"".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    pub(crate) fn validate_man_pages(config: &CliConfig) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if config.man_pages && config.layout == Layout::SingleFile {
+            errors.push(ValidationError::new(
+                "/man_pages",
+                "Man pages need a library crate; use a layout other than single-file",
+            ));
+        }
+        errors
+    }
+}
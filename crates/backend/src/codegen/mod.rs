@@ -1 +1,25 @@
+pub mod architecture;
+pub mod ci;
+pub mod cli_tests;
+pub mod commands;
+pub mod completions;
+pub mod dependency;
+pub mod entry_point;
+pub mod environment;
+pub mod errors;
+pub mod examples;
 pub mod generators;
+pub mod globals;
+pub mod groups;
+pub mod layout;
+pub mod logging;
+pub mod man;
+pub mod plan;
+pub mod readme;
+pub mod settings;
+pub mod snapshots;
+pub mod update;
+pub mod validation;
+pub mod value_enums;
+pub mod vars;
+pub mod xtask;
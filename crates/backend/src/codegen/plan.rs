@@ -0,0 +1,141 @@
+use crate::codegen::generators::{CodeGenerator, GeneratedFile};
+use crate::models::config::CliConfig;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+/// What writing a generated file would do to the output directory.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileAction {
+    Create,
+    Modify,
+    Unchanged,
+}
+
+impl fmt::Display for FileAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            FileAction::Create => "create",
+            FileAction::Modify => "modify",
+            FileAction::Unchanged => "unchanged",
+        };
+        f.pad(label)
+    }
+}
+
+/// A generated file compared against what is currently on disk.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PlannedFile {
+    pub file: GeneratedFile,
+    pub action: FileAction,
+    /// Content currently on disk, if the file exists and is readable as text
+    pub existing: Option<String>,
+    /// Mode currently on disk, if the file exists
+    pub existing_mode: Option<u32>,
+}
+
+impl PlannedFile {
+    /// Unified diff from the file on disk to the generated content.
+    pub fn diff(&self) -> Option<String> {
+        if self.action == FileAction::Unchanged {
+            return None;
+        }
+
+        let old = self.existing.as_deref().unwrap_or("");
+        let patch = diffy::create_patch(old, &self.file.content);
+        let old_label = if self.existing.is_some() {
+            format!("a/{}", self.file.path)
+        } else {
+            "/dev/null".to_string()
+        };
+
+        let mut diff = format!("--- {}\n+++ b/{}\n", old_label, self.file.path);
+        if let Some(mode) = self.existing_mode.filter(|mode| *mode != self.file.mode) {
+            diff = format!(
+                "old mode {:o}\nnew mode {:o}\n{}",
+                mode, self.file.mode, diff
+            );
+        }
+        // Skip diffy's own `---`/`+++` header lines
+        for line in patch.to_string().lines().skip(2) {
+            diff.push_str(line);
+            diff.push('\n');
+        }
+        Some(diff)
+    }
+}
+
+impl CodeGenerator {
+    /// Render the project and compare each file against the output directory
+    /// without writing anything.
+    pub fn plan_files(
+        config: &CliConfig,
+        output_dir: &str,
+    ) -> Result<Vec<PlannedFile>, Box<dyn std::error::Error>> {
+        let files = Self::render_project(config, output_dir)?;
+        Ok(files
+            .into_iter()
+            .map(|file| Self::plan_file(file, output_dir))
+            .collect())
+    }
+
+    fn plan_file(file: GeneratedFile, output_dir: &str) -> PlannedFile {
+        let path = Path::new(output_dir).join(&file.path);
+        let existing_mode = fs::metadata(&path)
+            .ok()
+            .map(|metadata| metadata.permissions().mode() & 0o777);
+        let existing = existing_mode.and_then(|_| fs::read_to_string(&path).ok());
+
+        let action = match (existing_mode, &existing) {
+            (None, _) => FileAction::Create,
+            (Some(mode), Some(content)) if mode == file.mode && *content == file.content => {
+                FileAction::Unchanged
+            }
+            _ => FileAction::Modify,
+        };
+
+        PlannedFile {
+            file,
+            action,
+            existing,
+            existing_mode,
+        }
+    }
+
+    /// Print a dry-run report for `plan`, optionally followed by unified diffs.
+    pub fn print_plan(plan: &[PlannedFile], show_diff: bool) {
+        let width = plan
+            .iter()
+            .map(|planned| planned.file.path.len())
+            .max()
+            .unwrap_or(0);
+
+        for planned in plan {
+            println!(
+                "{:<9}  {:<width$}  {:>7} B  {:04o}",
+                planned.action,
+                planned.file.path,
+                planned.file.content.len(),
+                planned.file.mode,
+                width = width
+            );
+        }
+
+        let count = |action| plan.iter().filter(|p| p.action == action).count();
+        println!(
+            "\n{} to create, {} to modify, {} unchanged (dry run, nothing written)",
+            count(FileAction::Create),
+            count(FileAction::Modify),
+            count(FileAction::Unchanged)
+        );
+
+        if show_diff {
+            for diff in plan.iter().filter_map(PlannedFile::diff) {
+                println!();
+                print!("{}", diff);
+            }
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use crate::codegen::dependency::SW_CLI_DIR;
+use crate::codegen::generators::CodeGenerator;
+use crate::models::config::{ArgConfig, ArgKind, CliConfig, CommandConfig, SwCliDependency};
+
+impl CodeGenerator {
+    /// The long description, preceded by the short one unless it already
+    /// starts with it.
+    pub(crate) fn render_readme_description(config: &CliConfig) -> String {
+        let short = config.short_description.trim();
+        let long = config.long_description.trim();
+        if long.starts_with(short) {
+            long.to_string()
+        } else if long.is_empty() {
+            short.to_string()
+        } else {
+            format!("{}\n\n{}", short, long)
+        }
+    }
+
+    /// Usage line of the CLI itself, as clap prints it.
+    pub(crate) fn render_readme_usage(config: &CliConfig) -> String {
+        let mut usage = format!("{} [OPTIONS]", config.name);
+        for arg in config
+            .args
+            .iter()
+            .filter(|arg| arg.kind == ArgKind::Positional)
+        {
+            usage.push(' ');
+            usage.push_str(&Self::usage_value(arg));
+        }
+        if !config.commands.is_empty() {
+            usage.push_str(" [COMMAND]");
+        }
+        usage
+    }
+
+    /// Table of the top-level options, including the global ones.
+    pub(crate) fn render_readme_options(config: &CliConfig) -> String {
+        let mut rows: Vec<(String, String)> = Self::global_fields(config)
+            .iter()
+            .map(|field| (field.usage.to_string(), field.description.to_string()))
+            .collect();
+        rows.extend(config.args.iter().map(Self::readme_arg_row));
+        rows.push(("-h, --help".to_string(), "Print help".to_string()));
+        rows.push((
+            "-V, --version".to_string(),
+            "Print version information with build metadata".to_string(),
+        ));
+        Self::markdown_table(&rows)
+    }
+
+    /// `## Commands` section with the usage line and arguments of each command.
+    pub(crate) fn render_readme_commands(config: &CliConfig) -> String {
+        if config.commands.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::from("\n## Commands\n");
+        for command in &config.commands {
+            out.push_str(&format!("\n### `{}`\n\n", command.name));
+            if !command.description.is_empty() {
+                out.push_str(&format!("{}\n\n", command.description.trim_end()));
+            }
+            out.push_str(&format!(
+                "```text\n{}\n```\n",
+                Self::command_usage(config, command)
+            ));
+            if !command.args.is_empty() {
+                let rows: Vec<_> = command.args.iter().map(Self::readme_arg_row).collect();
+                out.push('\n');
+                out.push_str(&Self::markdown_table(&rows));
+                out.push('\n');
+            }
+        }
+        out.trim_end().to_string()
+    }
+
+    /// The configured examples, and invocations of each command without
+    /// examples with its required arguments.
+    pub(crate) fn render_readme_examples(config: &CliConfig) -> String {
+        let mut examples = vec![
+            format!("# Show all options\n{} --help", config.name),
+            format!("# Show version information\n{} --version", config.name),
+        ];
+        examples.extend(
+            config
+                .examples
+                .iter()
+                .map(|example| Self::format_example(config, example, "")),
+        );
+        for command in &config.commands {
+            if !command.examples.is_empty() {
+                examples.extend(
+                    command
+                        .examples
+                        .iter()
+                        .map(|example| Self::format_example(config, example, "")),
+                );
+                continue;
+            }
+            let mut line = format!("{} {}", config.name, command.name);
+            for arg in command.args.iter().filter(|arg| Self::is_required(arg)) {
+                line.push(' ');
+                line.push_str(&Self::usage_arg(arg));
+            }
+            let summary = command.description.lines().next().unwrap_or_default();
+            if summary.trim().is_empty() {
+                examples.push(line);
+            } else {
+                examples.push(format!("# {}\n{}", summary.trim(), line));
+            }
+        }
+        examples.join("\n\n")
+    }
+
+    /// Build instructions matching how the project depends on sw-cli.
+    pub(crate) fn render_readme_build(config: &CliConfig) -> String {
+        let (setup, build) = if config.xtask {
+            ("cargo xtask setup", "cargo xtask build")
+        } else {
+            ("./scripts/setup.sh", "./scripts/build.sh")
+        };
+        let binary = format!("target/release/{}", config.name);
+
+        let prerequisite = match &config.sw_cli_dependency {
+            SwCliDependency::Submodule => format!(
+                "sw-cli is a git submodule at `{}`. Clone with `--recursive`, or let\n`{}` add and initialize it.",
+                SW_CLI_DIR, setup
+            ),
+            SwCliDependency::Git { .. } => {
+                "sw-cli is a git dependency that cargo fetches on the first build.".to_string()
+            }
+            SwCliDependency::Registry { .. } => {
+                "sw-cli is fetched from crates.io on the first build.".to_string()
+            }
+            SwCliDependency::Path { path } => format!(
+                "The build expects an sw-cli checkout at `{}`, relative to this directory.",
+                path
+            ),
+            SwCliDependency::Vendored { .. } => format!(
+                "The sw-cli sources are vendored in `{}`; no network access is needed.",
+                SW_CLI_DIR
+            ),
+            SwCliDependency::Standalone => "The project only depends on crates.io.".to_string(),
+        };
+
+        format!(
+            "{}\n\n```bash\n{}\n{}\n```\n\nThe binary is written to `{}`. To install it into `~/.cargo/bin`:\n\n```bash\ncargo install --path {}\n```",
+            prerequisite,
+            setup,
+            build,
+            binary,
+            match Self::bin_dir(config).trim_end_matches('/') {
+                "" => ".",
+                dir => dir,
+            }
+        )
+    }
+
+    /// Optional sections on shell completions and man pages.
+    pub(crate) fn render_readme_extras(config: &CliConfig) -> String {
+        let mut out = String::new();
+        if config.completions {
+            out.push_str(&format!(
+                "\n## Shell Completions\n\n```bash\n{name} completions bash > ~/.local/share/bash-completion/completions/{name}\n{name} completions zsh > ~/.zfunc/_{name}\n{name} completions fish > ~/.config/fish/completions/{name}.fish\n```\n",
+                name = config.name
+            ));
+        }
+        out.push_str(&Self::render_readme_settings(config));
+        if config.man_pages {
+            out.push_str(&format!(
+                "\n## Man Pages\n\n```bash\ncargo run --package {} --example mangen -- target/man\nman target/man/{}.1\n```\n",
+                Self::man_package(config),
+                config.name
+            ));
+        }
+        out.trim_end().to_string()
+    }
+
+    /// `add [OPTIONS] <TEXT>`-style usage line of `command`.
+    fn command_usage(config: &CliConfig, command: &CommandConfig) -> String {
+        let mut usage = format!("{} {}", config.name, command.name);
+        if command
+            .args
+            .iter()
+            .any(|arg| arg.kind != ArgKind::Positional)
+        {
+            usage.push_str(" [OPTIONS]");
+        }
+        for arg in command
+            .args
+            .iter()
+            .filter(|arg| arg.kind == ArgKind::Positional)
+        {
+            usage.push(' ');
+            usage.push_str(&Self::usage_value(arg));
+        }
+        usage
+    }
+
+    /// One table row: the flag or value as shown by `--help`, and its description.
+    fn readme_arg_row(arg: &ArgConfig) -> (String, String) {
+        let mut description = arg
+            .description
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        if !arg.values.is_empty() {
+            let values: Vec<String> = arg
+                .values
+                .iter()
+                .map(|value| format!("`{}`", value.name))
+                .collect();
+            description.push_str(&format!(" (one of {})", values.join(", ")));
+        }
+        if let Some(default_value) = &arg.default_value {
+            description.push_str(&format!(" (default: {})", default_value));
+        }
+        if arg.multiple {
+            description.push_str(" (repeatable)");
+        }
+        (Self::usage_arg(arg), description.trim().to_string())
+    }
+
+    /// `-p, --priority <PRIORITY>` for options, `<TEXT>` for positionals.
+    pub(crate) fn usage_arg(arg: &ArgConfig) -> String {
+        if arg.kind == ArgKind::Positional {
+            return Self::usage_value(arg);
+        }
+        let long = Self::long_flag(arg);
+        let mut flag = match arg.short {
+            Some(short) => format!("-{}, --{}", short, long),
+            None => format!("--{}", long),
+        };
+        if arg.kind == ArgKind::Option {
+            flag.push_str(&format!(" <{}>", Self::value_name(arg)));
+        }
+        flag
+    }
+
+    /// `<TEXT>`, `[TEXT]` or `[TEXT]...`, following clap's notation.
+    fn usage_value(arg: &ArgConfig) -> String {
+        let name = Self::value_name(arg);
+        let value = if arg.required && arg.default_value.is_none() {
+            format!("<{}>", name)
+        } else {
+            format!("[{}]", name)
+        };
+        if arg.multiple { value + "..." } else { value }
+    }
+
+    fn value_name(arg: &ArgConfig) -> String {
+        arg.value_name
+            .clone()
+            .unwrap_or_else(|| arg.name.to_uppercase())
+    }
+
+    fn markdown_table(rows: &[(String, String)]) -> String {
+        let mut out = String::from("| Argument | Description |\n|----------|-------------|\n");
+        for (usage, description) in rows {
+            out.push_str(&format!(
+                "| `{}` | {} |\n",
+                usage,
+                description.replace('|', "\\|")
+            ));
+        }
+        out.trim_end().to_string()
+    }
+}
@@ -0,0 +1,166 @@
+use crate::codegen::generators::{CodeGenerator, FILE_MODE};
+use crate::codegen::globals::GlobalField;
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{CliConfig, CommandConfig, ErrorHandling, GlobalOption, Layout};
+
+/// Name of the built-in subcommand printing the effective settings.
+pub const SETTINGS_COMMAND: &str = "config";
+
+impl CodeGenerator {
+    /// `settings.rs` next to the error module, as (template name, output
+    /// path, mode).
+    pub(crate) fn settings_outputs(
+        config: &CliConfig,
+        lib_src: &str,
+    ) -> Vec<(&'static str, String, u32)> {
+        if !config.settings {
+            return Vec::new();
+        }
+        vec![("settings.rs", format!("{}/settings.rs", lib_src), FILE_MODE)]
+    }
+
+    pub(crate) fn is_settings_command(config: &CliConfig, command: &CommandConfig) -> bool {
+        config.settings && command.name == SETTINGS_COMMAND
+    }
+
+    /// The `--set KEY=VALUE` option, the last layer of the settings.
+    pub(crate) fn settings_field(config: &CliConfig) -> Option<GlobalField> {
+        config.settings.then(|| GlobalField {
+            name: "set",
+            ty: "Vec<String>",
+            default: "Vec::new()",
+            short: None,
+            long: "set",
+            attrs: "long, value_name = \"KEY=VALUE\"".into(),
+            description: "Override a setting (repeatable)",
+            usage: "--set <KEY=VALUE>",
+        })
+    }
+
+    /// Arguments of `Settings::load` taken from the parsed `receiver`.
+    pub(crate) fn render_settings_args(config: &CliConfig, receiver: &str) -> String {
+        let config_file = if config.global_options.contains(&GlobalOption::ConfigFile) {
+            format!("{}.config.as_deref()", receiver)
+        } else {
+            "None".to_string()
+        };
+        format!("{}, &{}.set", config_file, receiver)
+    }
+
+    /// Loading the settings in `main` of the simple architecture, and
+    /// handling the `config` subcommand next to that of `completions`.
+    pub(crate) fn render_main_settings(config: &CliConfig) -> String {
+        if !config.settings {
+            return String::new();
+        }
+        // Loading errors get the exit code of `Error::Settings`
+        let failure = match config.error_handling {
+            ErrorHandling::None => "std::process::exit(1);",
+            _ => "return error::exit_code(&e.into());",
+        };
+        format!(
+            r#"
+    let settings = match Settings::load({}) {{
+        Ok(settings) => settings,
+        Err(e) => {{
+            eprintln!("Error: {{}}", e);
+            {}
+        }}
+    }};
+
+    if let Some(Commands::Config) = &cli.command {{
+        print!("{{}}", settings);
+        return{};
+    }}"#,
+            Self::render_settings_args(config, "cli"),
+            failure,
+            Self::render_main_success(config)
+        )
+    }
+
+    /// `CommandHandler` implementation of the dispatch architecture.
+    pub(crate) fn render_settings_handler(config: &CliConfig) -> String {
+        format!(
+            r#"
+impl CommandHandler for crate::config::ConfigCommand {{
+    fn handle(&self, config: &CliConfig) -> {} {{
+        let settings = crate::settings::Settings::load({})?;
+        print!("{{}}", settings);
+        Ok(())
+    }}
+}}
+"#,
+            Self::result_type(config),
+            Self::render_settings_args(config, "config")
+        )
+    }
+
+    /// The single-file layout compiles the settings module into the binary,
+    /// where the accessors nobody calls yet would be dead code.
+    pub(crate) fn render_settings_lints(config: &CliConfig) -> String {
+        if config.layout == Layout::SingleFile {
+            "\n// `get` and `source` are there for the commands to use\n#![allow(dead_code)]"
+                .to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// The CONFIGURATION section of `long-help.txt`.
+    pub(crate) fn render_settings_help(config: &CliConfig) -> String {
+        if !config.settings {
+            return String::new();
+        }
+        let config_file = if config.global_options.contains(&GlobalOption::ConfigFile) {
+            "      --config <FILE>\n"
+        } else {
+            ""
+        };
+        format!(
+            r#"CONFIGURATION:
+    Settings are merged from, in increasing order of precedence:
+      $XDG_CONFIG_HOME/{name}/config.toml (default ~/.config/{name}/config.toml)
+      .{name}.toml in the current directory
+{config_file}      {prefix}* environment variables, e.g. {prefix}SERVER__PORT for server.port
+      --set KEY=VALUE
+    Run `{name} {command}` to print the effective settings and their sources.
+"#,
+            name = config.name,
+            config_file = config_file,
+            prefix = Self::env_prefix(config),
+            command = SETTINGS_COMMAND
+        )
+    }
+
+    /// The Configuration section of the generated `README.md`.
+    pub(crate) fn render_readme_settings(config: &CliConfig) -> String {
+        if !config.settings {
+            return String::new();
+        }
+        format!(
+            "\n## Configuration\n\nSettings are read from `~/.config/{name}/config.toml`, then `.{name}.toml` in the current directory, `{prefix}*` environment variables (`__` separates nested keys) and `--set KEY=VALUE`, later ones taking precedence:\n\n```toml\n[server]\nport = 8080\n```\n\n```bash\n{prefix}SERVER__PORT=9090 {name} {command}\n```\n",
+            name = config.name,
+            prefix = Self::env_prefix(config),
+            command = SETTINGS_COMMAND
+        )
+    }
+
+    pub(crate) fn validate_settings(config: &CliConfig) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if let Some(i) = config
+            .commands
+            .iter()
+            .position(|command| command.name == SETTINGS_COMMAND)
+            && config.settings
+        {
+            errors.push(ValidationError::new(
+                format!("/commands/{}/name", i),
+                format!(
+                    "Command name '{}' is reserved when settings are enabled",
+                    SETTINGS_COMMAND
+                ),
+            ));
+        }
+        errors
+    }
+}
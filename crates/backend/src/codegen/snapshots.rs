@@ -0,0 +1,83 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::models::config::CliConfig;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Directory of the help snapshots, relative to the binary crate.
+const SNAPSHOT_DIR: &str = "tests/snapshots";
+
+impl CodeGenerator {
+    /// `#[test]` functions of the generated `tests/help.rs`, one per command.
+    pub(crate) fn render_help_snapshot_tests(config: &CliConfig) -> String {
+        let mut out =
+            String::from("\n#[test]\nfn help_matches_snapshot() {\n    assert_help(&[]);\n}\n");
+        for command in &config.commands {
+            out.push_str(&format!(
+                "\n#[test]\nfn {}_help_matches_snapshot() {{\n    assert_help(&[\"{}\"]);\n}}\n",
+                command.name.replace('-', "_"),
+                command.name
+            ));
+        }
+        out.trim_end().to_string()
+    }
+
+    /// File names of the help snapshots `tests/help.rs` records.
+    fn snapshot_files(config: &CliConfig) -> Vec<String> {
+        let mut files = vec!["help.txt".to_string()];
+        files.extend(
+            config
+                .commands
+                .iter()
+                .map(|command| format!("{}-help.txt", command.name)),
+        );
+        files
+    }
+
+    /// Re-record the help snapshots of a generated project by running its
+    /// snapshot tests with `UPDATE_SNAPSHOTS=1`. Returns the snapshot files.
+    pub fn refresh_snapshots(output_dir: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let manifest = Self::read_manifest(output_dir)?;
+        // The snapshots of `completions` and `config` are not stale
+        let config = &Self::with_builtin_commands(&manifest.config);
+        if !config.snapshot_tests {
+            return Err(format!(
+                "{} was generated without snapshot tests; enable `snapshot_tests` and run `cli-gen update` first",
+                output_dir
+            )
+            .into());
+        }
+
+        eprintln!("[DEBUG] Running snapshot tests in {}", output_dir);
+        let status = Command::new("cargo")
+            .args(["test", "-p", &config.name, "--test", "help"])
+            .env("UPDATE_SNAPSHOTS", "1")
+            .current_dir(output_dir)
+            .status()
+            .map_err(|e| format!("Failed to run cargo: {}", e))?;
+        if !status.success() {
+            return Err(format!("Snapshot tests failed in {}", output_dir).into());
+        }
+
+        // Only now drop the snapshots of removed commands, so a failed run
+        // leaves the old ones in place
+        let snapshot_dir = Path::new(output_dir)
+            .join(Self::bin_dir(config))
+            .join(SNAPSHOT_DIR);
+        let current = Self::snapshot_files(config);
+        let mut snapshots = Vec::new();
+        for entry in fs::read_dir(&snapshot_dir)? {
+            let path = entry?.path();
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+            let stale = path.extension().is_some_and(|ext| ext == "txt")
+                && !current.iter().any(|current| *current == file_name);
+            if stale {
+                fs::remove_file(&path)?;
+            } else {
+                snapshots.push(path.display().to_string());
+            }
+        }
+        snapshots.sort();
+        Ok(snapshots)
+    }
+}
@@ -0,0 +1,218 @@
+use crate::codegen::generators::{CodeGenerator, GeneratedFile};
+use crate::models::config::CliConfig;
+use crate::models::manifest::{BASE_DIR, MANIFEST_FILE, ProjectManifest};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Outcome of updating a single generated file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpdateStatus {
+    /// New rendering matches the file on disk
+    Unchanged,
+    /// File was untouched locally and replaced by the new rendering
+    Updated,
+    /// File was edited locally and the template output did not change
+    Kept,
+    /// Local edits and template changes were merged cleanly
+    Merged,
+    /// Local edits and template changes overlap; conflict markers written
+    Conflict,
+    /// File did not exist before and was created
+    Created,
+    /// File was deleted locally and is left deleted
+    DeletedLocally,
+    /// File is no longer produced by the templates and is left in place
+    Orphaned,
+}
+
+impl fmt::Display for UpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            UpdateStatus::Unchanged => "unchanged",
+            UpdateStatus::Updated => "updated",
+            UpdateStatus::Kept => "kept",
+            UpdateStatus::Merged => "merged",
+            UpdateStatus::Conflict => "conflict",
+            UpdateStatus::Created => "created",
+            UpdateStatus::DeletedLocally => "deleted",
+            UpdateStatus::Orphaned => "orphaned",
+        };
+        f.pad(label)
+    }
+}
+
+/// Result of `CodeGenerator::update_project` for one file.
+#[derive(Clone, Debug)]
+pub struct FileUpdate {
+    pub path: String,
+    pub status: UpdateStatus,
+}
+
+/// Summary of an update run.
+#[derive(Clone, Debug)]
+pub struct UpdateReport {
+    pub previous_template_version: String,
+    pub template_version: String,
+    pub files: Vec<FileUpdate>,
+}
+
+impl UpdateReport {
+    pub fn conflicts(&self) -> usize {
+        self.files
+            .iter()
+            .filter(|file| file.status == UpdateStatus::Conflict)
+            .count()
+    }
+}
+
+impl CodeGenerator {
+    /// Read the manifest of a previously generated project.
+    pub fn read_manifest(output_dir: &str) -> Result<ProjectManifest, Box<dyn std::error::Error>> {
+        let manifest_path = Path::new(output_dir).join(MANIFEST_FILE);
+        let content = fs::read_to_string(&manifest_path).map_err(|e| {
+            format!(
+                "Failed to read {}: {} (was this project generated by cli-gen?)",
+                manifest_path.display(),
+                e
+            )
+        })?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    /// Re-render a generated project with the current templates and three-way
+    /// merge the result into the files on disk, using the base copies recorded
+    /// at the last generation as the common ancestor.
+    ///
+    /// When `config` is `None` the configuration recorded in the manifest is
+    /// reused. With `dry_run` nothing is written.
+    pub fn update_project(
+        output_dir: &str,
+        config: Option<CliConfig>,
+        dry_run: bool,
+    ) -> Result<UpdateReport, Box<dyn std::error::Error>> {
+        let manifest = Self::read_manifest(output_dir)?;
+        let config = config.unwrap_or(manifest.config);
+        Self::validate_config(&config)?;
+
+        let files = Self::render_files(&config)?;
+        let root = Path::new(output_dir);
+        let mut writes = Vec::new();
+        let mut report = UpdateReport {
+            previous_template_version: manifest.template_version,
+            template_version: Self::template_version()?,
+            files: Vec::new(),
+        };
+
+        for file in &files {
+            let base = fs::read_to_string(root.join(BASE_DIR).join(&file.path)).ok();
+            let ours = fs::read_to_string(root.join(&file.path)).ok();
+
+            let (status, content) = Self::merge_file(base, ours, &file.content);
+            if let Some(content) = content {
+                writes.push(GeneratedFile {
+                    path: file.path.clone(),
+                    content,
+                    mode: file.mode,
+                });
+            }
+            report.files.push(FileUpdate {
+                path: file.path.clone(),
+                status,
+            });
+        }
+
+        // Files recorded at the last generation that the templates no longer produce
+        for path in Self::base_paths(&root.join(BASE_DIR))? {
+            if !files.iter().any(|file| file.path == path) {
+                let status = UpdateStatus::Orphaned;
+                report.files.push(FileUpdate { path, status });
+            }
+        }
+
+        if !dry_run {
+            Self::apply_update(output_dir, &config, &files, &writes)?;
+        }
+        Ok(report)
+    }
+
+    /// Write the merged files, and `files` as the new base copies.
+    fn apply_update(
+        output_dir: &str,
+        config: &CliConfig,
+        files: &[GeneratedFile],
+        writes: &[GeneratedFile],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        for file in writes {
+            Self::write_file(output_dir, file)?;
+        }
+
+        // The new rendering becomes the ancestor for the next update
+        let base_dir = Path::new(output_dir).join(BASE_DIR);
+        if base_dir.exists() {
+            fs::remove_dir_all(&base_dir)?;
+        }
+        for file in Self::metadata_files(config, files, output_dir)? {
+            Self::write_file(output_dir, &file)?;
+        }
+        Ok(())
+    }
+
+    /// Status of a file given its `base` copy, the file on disk (`ours`)
+    /// and its new rendering, with the content to write if any.
+    fn merge_file(
+        base: Option<String>,
+        ours: Option<String>,
+        rendered: &str,
+    ) -> (UpdateStatus, Option<String>) {
+        match (base, ours) {
+            (_, Some(ours)) if ours == rendered => (UpdateStatus::Unchanged, None),
+            (Some(_), None) => (UpdateStatus::DeletedLocally, None),
+            (None, None) => (UpdateStatus::Created, Some(rendered.to_string())),
+            (Some(base), Some(ours)) if base == ours => {
+                (UpdateStatus::Updated, Some(rendered.to_string()))
+            }
+            (Some(base), Some(_)) if base == rendered => (UpdateStatus::Kept, None),
+            // Without a recorded ancestor every difference is a conflict
+            (base, Some(ours)) => {
+                match diffy::merge(base.as_deref().unwrap_or(""), &ours, rendered) {
+                    Ok(merged) => (UpdateStatus::Merged, Some(merged)),
+                    Err(conflicted) => (UpdateStatus::Conflict, Some(conflicted)),
+                }
+            }
+        }
+    }
+
+    /// Relative paths of every base copy below `base_dir`.
+    fn base_paths(base_dir: &Path) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        if !base_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut paths = Vec::new();
+        for path in Self::walk_files(base_dir)? {
+            let relative = path.strip_prefix(base_dir)?;
+            paths.push(relative.to_string_lossy().replace('\\', "/"));
+        }
+        Ok(paths)
+    }
+
+    /// Print the per-file outcome of an update.
+    pub fn print_update_report(report: &UpdateReport) {
+        println!(
+            "Templates: {} -> {}",
+            report.previous_template_version, report.template_version
+        );
+        for file in &report.files {
+            println!("{:<9}  {}", file.status, file.path);
+        }
+
+        let conflicts = report.conflicts();
+        if conflicts > 0 {
+            println!(
+                "\n{} file(s) have conflicts; resolve the <<<<<<< / >>>>>>> markers before building",
+                conflicts
+            );
+        }
+    }
+}
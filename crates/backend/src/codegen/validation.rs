@@ -0,0 +1,311 @@
+use crate::codegen::commands::RUST_KEYWORDS;
+use crate::codegen::generators::CodeGenerator;
+use crate::models::config::{ArgConfig, ArgKind, CliConfig, CommandConfig};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Short flags clap itself claims on `Cli`.
+const BUILTIN_SHORTS: [char; 2] = ['h', 'V'];
+/// Long flags clap itself claims on `Cli`.
+const BUILTIN_LONGS: [&str; 2] = ["help", "version"];
+
+/// A reason a configuration cannot be rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// JSON pointer of the offending value, e.g. `/commands/0/name`
+    pub path: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    pub(crate) fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        ValidationError {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl CodeGenerator {
+    pub fn validate_config(config: &CliConfig) -> Result<(), String> {
+        match Self::validation_errors(config).into_iter().next() {
+            Some(error) => Err(error.message),
+            None => Ok(()),
+        }
+    }
+
+    /// Every problem found by each check of `config`, so that unrelated
+    /// mistakes are reported together.
+    pub fn validation_errors(config: &CliConfig) -> Vec<ValidationError> {
+        [
+            Self::validate_descriptions(config),
+            Self::validate_dependency(config),
+            Self::validate_global_options(config),
+            Self::validate_logging(config),
+            Self::validate_error_handling(config),
+            Self::validate_commands(config),
+            Self::validate_env(config),
+            Self::validate_value_enums(config),
+            Self::validate_examples(config),
+            Self::validate_architecture(config),
+            Self::validate_layout(config),
+            Self::validate_completions(config),
+            Self::validate_settings(config),
+            Self::validate_man_pages(config),
+        ]
+        .concat()
+    }
+
+    fn validate_descriptions(config: &CliConfig) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if config.name.is_empty() {
+            errors.push(ValidationError::new("/name", "CLI name cannot be empty"));
+        }
+
+        if config.short_description.is_empty() {
+            errors.push(ValidationError::new(
+                "/short_description",
+                "Short description cannot be empty",
+            ));
+        }
+
+        if config.long_description.is_empty() {
+            errors.push(ValidationError::new(
+                "/long_description",
+                "Long description cannot be empty",
+            ));
+        }
+
+        errors
+    }
+
+    /// Every argument of the CLI and of its commands, with the command it
+    /// belongs to and its JSON pointer.
+    pub(crate) fn located_args(
+        config: &CliConfig,
+    ) -> Vec<(Option<&CommandConfig>, String, &ArgConfig)> {
+        let top_level = config
+            .args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| (None, format!("/args/{}", i), arg));
+        let commands =
+            config.commands.iter().enumerate().flat_map(|(i, command)| {
+                command.args.iter().enumerate().map(move |(j, arg)| {
+                    (Some(command), format!("/commands/{}/args/{}", i, j), arg)
+                })
+            });
+        top_level.chain(commands).collect()
+    }
+
+    /// Check that commands and arguments can be rendered into valid clap code.
+    pub(crate) fn validate_commands(config: &CliConfig) -> Vec<ValidationError> {
+        // The global options and the subcommand field are defined next to the arguments
+        let globals = Self::global_fields(config);
+        let mut reserved_fields: Vec<&str> = globals.iter().map(|field| field.name).collect();
+        reserved_fields.push("command");
+        let mut reserved_shorts: Vec<char> =
+            globals.iter().filter_map(|field| field.short).collect();
+        reserved_shorts.extend(BUILTIN_SHORTS);
+        let mut reserved_longs: Vec<&str> = globals.iter().map(|field| field.long).collect();
+        reserved_longs.extend(BUILTIN_LONGS);
+        let mut errors = Self::validate_args(
+            &config.args,
+            "",
+            "the CLI",
+            &reserved_fields,
+            &reserved_shorts,
+            &reserved_longs,
+        );
+        let global_names: Vec<&str> = globals.iter().map(|field| field.name).collect();
+        errors.extend(Self::validate_relations(
+            &config.args,
+            &config.groups,
+            "",
+            "the CLI",
+            &global_names,
+        ));
+
+        let mut names = HashSet::new();
+        for (i, command) in config.commands.iter().enumerate() {
+            errors.extend(Self::validate_command(
+                command,
+                &format!("/commands/{}", i),
+                &mut names,
+            ));
+        }
+
+        errors
+    }
+
+    /// Check the name, arguments and groups of the command at `path`.
+    fn validate_command(
+        command: &CommandConfig,
+        path: &str,
+        names: &mut HashSet<String>,
+    ) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if let Err(error) = Self::validate_command_name(command, names) {
+            errors.push(ValidationError::new(format!("{}/name", path), error));
+        }
+        let scope = format!("command '{}'", command.name);
+        errors.extend(Self::validate_args(
+            &command.args,
+            path,
+            &scope,
+            &[],
+            &['h'],
+            &["help"],
+        ));
+        errors.extend(Self::validate_relations(
+            &command.args,
+            &command.groups,
+            path,
+            &scope,
+            &[],
+        ));
+        errors
+    }
+
+    fn validate_command_name(
+        command: &CommandConfig,
+        names: &mut HashSet<String>,
+    ) -> Result<(), String> {
+        if command.name.is_empty() {
+            return Err("Command name cannot be empty".to_string());
+        }
+        if !Self::is_kebab_case(&command.name) {
+            return Err(format!(
+                "Command name '{}' must be lowercase letters, digits and dashes",
+                command.name
+            ));
+        }
+        // `self` would become the `Self` variant
+        if command.name == "self" {
+            return Err("Command name 'self' is a Rust keyword".to_string());
+        }
+        if command.name == "help" {
+            return Err("Command name 'help' is reserved for clap's help subcommand".to_string());
+        }
+        // `add-1` and `add1` would both become the `Add1` variant
+        if !names.insert(Self::variant_name(&command.name)) {
+            return Err(format!("Duplicate command '{}'", command.name));
+        }
+        Ok(())
+    }
+
+    /// Check the names and flags of `args` of the CLI or command at the JSON
+    /// pointer `path`, described as `scope` in messages.
+    fn validate_args(
+        args: &[ArgConfig],
+        path: &str,
+        scope: &str,
+        reserved_fields: &[&str],
+        reserved_shorts: &[char],
+        reserved_longs: &[&str],
+    ) -> Vec<ValidationError> {
+        let mut fields = HashSet::new();
+        let mut shorts = HashSet::new();
+        let mut longs = HashSet::new();
+        let mut errors = Vec::new();
+
+        for (i, arg) in args.iter().enumerate() {
+            let path = format!("{}/args/{}", path, i);
+            if let Err(message) = Self::validate_arg_name(arg, scope) {
+                errors.push(ValidationError::new(format!("{}/name", path), message));
+            } else if reserved_fields.contains(&arg.name.as_str())
+                || !fields.insert(arg.name.as_str())
+            {
+                errors.push(ValidationError::new(
+                    format!("{}/name", path),
+                    format!("Duplicate argument '{}' in {}", arg.name, scope),
+                ));
+            }
+            if arg.kind == ArgKind::Positional {
+                continue;
+            }
+
+            if let Some(short) = arg.short {
+                let taken = reserved_shorts.contains(&short) || !shorts.insert(short);
+                errors.extend(Self::validate_short(arg, short, taken, &path, scope));
+            }
+            let long = Self::long_flag(arg);
+            let taken = reserved_longs.contains(&long.as_str()) || !longs.insert(long.clone());
+            errors.extend(Self::validate_long(arg, &long, taken, &path, scope));
+        }
+
+        errors
+    }
+
+    /// Check the `short` flag of the argument at `path`, `taken` if another
+    /// argument of `scope` uses it as well.
+    fn validate_short(
+        arg: &ArgConfig,
+        short: char,
+        taken: bool,
+        path: &str,
+        scope: &str,
+    ) -> Option<ValidationError> {
+        let message = if !short.is_ascii_alphanumeric() {
+            format!(
+                "Short flag '-{}' of '{}' in {} must be a letter or digit",
+                short, arg.name, scope
+            )
+        } else if taken {
+            format!(
+                "Short flag '-{}' of '{}' in {} is already in use",
+                short, arg.name, scope
+            )
+        } else {
+            return None;
+        };
+        Some(ValidationError::new(format!("{}/short", path), message))
+    }
+
+    /// Check the `long` flag of the argument at `path`, `taken` if another
+    /// argument of `scope` uses it as well.
+    fn validate_long(
+        arg: &ArgConfig,
+        long: &str,
+        taken: bool,
+        path: &str,
+        scope: &str,
+    ) -> Option<ValidationError> {
+        let message = if !Self::is_long_flag(long) {
+            format!(
+                "Long flag '--{}' of '{}' in {} must be letters, digits, dashes and underscores",
+                long, arg.name, scope
+            )
+        } else if taken {
+            format!(
+                "Long flag '--{}' of '{}' in {} is already in use",
+                long, arg.name, scope
+            )
+        } else {
+            return None;
+        };
+        Some(ValidationError::new(format!("{}/long", path), message))
+    }
+
+    fn validate_arg_name(arg: &ArgConfig, scope: &str) -> Result<(), String> {
+        if !Self::is_snake_case(&arg.name) {
+            return Err(format!(
+                "Argument name '{}' in {} must be a snake_case identifier",
+                arg.name, scope
+            ));
+        }
+        if RUST_KEYWORDS.contains(&arg.name.as_str()) {
+            return Err(format!(
+                "Argument name '{}' in {} is a Rust keyword; rename it and set `long` to keep the flag",
+                arg.name, scope
+            ));
+        }
+        Ok(())
+    }
+}
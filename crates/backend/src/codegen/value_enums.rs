@@ -0,0 +1,155 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::codegen::validation::ValidationError;
+use crate::models::config::{ArgConfig, ArgKind, CliConfig, CommandConfig, Layout};
+use std::collections::HashSet;
+
+/// Types the generated parser and dispatch modules define themselves, next
+/// to the `<Variant>Command` structs of the commands.
+const RESERVED_TYPES: [&str; 7] = [
+    "Cli",
+    "CliConfig",
+    "CliConfigBuilder",
+    "Commands",
+    "OutputFormat",
+    "CliDispatcher",
+    "CommandHandler",
+];
+
+impl CodeGenerator {
+    /// Name of the `ValueEnum` generated for `arg`: `Format` for a top-level
+    /// `format` argument, `ListFormat` for that of the `list` command.
+    pub(crate) fn value_enum_name(command: Option<&CommandConfig>, arg: &ArgConfig) -> String {
+        match command {
+            Some(command) => format!(
+                "{}{}",
+                Self::variant_name(&command.name),
+                Self::variant_name(&arg.name)
+            ),
+            None => Self::variant_name(&arg.name),
+        }
+    }
+
+    /// The value enums of the parser module: those of the top-level
+    /// arguments, and of the commands unless each has its own module.
+    pub(crate) fn render_value_enums(config: &CliConfig) -> String {
+        let mut out = String::new();
+        for arg in &config.args {
+            out.push_str(&Self::render_value_enum(None, arg));
+        }
+        if config.layout != Layout::CommandsDir {
+            for command in &config.commands {
+                out.push_str(&Self::render_command_value_enums(command));
+            }
+        }
+        out.trim_end().to_string()
+    }
+
+    /// The value enums of the arguments of `command`.
+    pub(crate) fn render_command_value_enums(command: &CommandConfig) -> String {
+        command
+            .args
+            .iter()
+            .map(|arg| Self::render_value_enum(Some(command), arg))
+            .collect()
+    }
+
+    /// A documented `clap::ValueEnum` enum with one variant per value of
+    /// `arg`, or nothing if it takes any value.
+    fn render_value_enum(command: Option<&CommandConfig>, arg: &ArgConfig) -> String {
+        if arg.values.is_empty() {
+            return String::new();
+        }
+        let owner = match command {
+            Some(command) => format!(" of the `{}` command", command.name),
+            None => String::new(),
+        };
+        let mut out = format!("\n/// Values of the `{}` argument{}.\n", arg.name, owner);
+        out.push_str("#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]\n");
+        out.push_str(&format!(
+            "pub enum {} {{\n",
+            Self::value_enum_name(command, arg)
+        ));
+        for value in &arg.values {
+            let variant = Self::variant_name(&value.name);
+            out.push_str(&Self::doc_comment(&value.description, "    "));
+            if Self::kebab_case(&variant) != value.name {
+                out.push_str(&format!("    #[value(name = \"{}\")]\n", value.name));
+            }
+            out.push_str(&format!("    {},\n", variant));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    pub(crate) fn validate_value_enums(config: &CliConfig) -> Vec<ValidationError> {
+        // The built-in commands get a struct of their own as well
+        let mut types: HashSet<String> = Self::with_builtin_commands(config)
+            .commands
+            .iter()
+            .map(|command| format!("{}Command", Self::variant_name(&command.name)))
+            .collect();
+        types.extend(RESERVED_TYPES.map(str::to_string));
+        let mut errors = Vec::new();
+        for (command, path, arg) in Self::located_args(config) {
+            if arg.values.is_empty() {
+                continue;
+            }
+            if !matches!(arg.kind, ArgKind::Option | ArgKind::Positional) {
+                errors.push(ValidationError::new(
+                    format!("{}/values", path),
+                    format!(
+                        "Argument '{}' takes no value and cannot list values",
+                        arg.name
+                    ),
+                ));
+                continue;
+            }
+            let name = Self::value_enum_name(command, arg);
+            if !types.insert(name.clone()) {
+                errors.push(ValidationError::new(
+                    format!("{}/values", path),
+                    format!(
+                        "Values of argument '{}' would generate a second type named {}",
+                        arg.name, name
+                    ),
+                ));
+            }
+            errors.extend(Self::validate_values(arg, &path));
+        }
+        errors
+    }
+
+    fn validate_values(arg: &ArgConfig, path: &str) -> Vec<ValidationError> {
+        let mut values = HashSet::new();
+        let mut errors = Vec::new();
+        for (i, value) in arg.values.iter().enumerate() {
+            let value_path = format!("{}/values/{}/name", path, i);
+            if !Self::is_kebab_case(&value.name) {
+                errors.push(ValidationError::new(
+                    value_path,
+                    format!(
+                        "Value '{}' of '{}' must be lowercase letters, digits and dashes",
+                        value.name, arg.name
+                    ),
+                ));
+            } else if !values.insert(Self::variant_name(&value.name)) {
+                errors.push(ValidationError::new(
+                    value_path,
+                    format!("Duplicate value '{}' of '{}'", value.name, arg.name),
+                ));
+            }
+        }
+        if let Some(default_value) = &arg.default_value
+            && !arg.values.iter().any(|value| value.name == *default_value)
+        {
+            errors.push(ValidationError::new(
+                format!("{}/default_value", path),
+                format!(
+                    "Default value '{}' of '{}' is not one of its values",
+                    default_value, arg.name
+                ),
+            ));
+        }
+        errors
+    }
+}
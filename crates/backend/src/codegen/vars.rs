@@ -0,0 +1,256 @@
+use crate::codegen::generators::CodeGenerator;
+use crate::models::config::{
+    Architecture, CliConfig, ErrorHandling, Layout, LicenseType, SwCliDependency,
+};
+
+type Vars = Vec<(&'static str, String)>;
+
+impl CodeGenerator {
+    /// Placeholder values substituted into every template, in substitution
+    /// order.
+    pub(crate) fn template_vars(config: &CliConfig) -> Vars {
+        [
+            Self::package_vars(config),
+            Self::main_vars(config),
+            Self::scaffold_vars(config),
+            Self::project_vars(config),
+            Self::parser_vars(config),
+        ]
+        .concat()
+    }
+
+    /// Package metadata, and the manifest and `lib.rs` entries that depend on it.
+    fn package_vars(config: &CliConfig) -> Vars {
+        // Get license string for Cargo.toml
+        let license_str = match &config.license {
+            LicenseType::MIT => "MIT",
+            LicenseType::Apache2 => "Apache-2.0",
+            LicenseType::GPL3 => "GPL-3.0",
+            LicenseType::Custom(s) => s.as_str(),
+        };
+
+        // Convert package name to valid crate name (replace - with _)
+        let crate_name = config.name.replace("-", "_");
+
+        vec![
+            ("name", config.name.clone()),
+            ("crate_name", crate_name),
+            ("lib_crate", Self::lib_crate(config)),
+            ("project_root", Self::project_root(config)),
+            ("short_description", config.short_description.clone()),
+            ("long_description", config.long_description.clone()),
+            ("author", config.author.clone()),
+            ("copyright", config.copyright.clone()),
+            ("license", license_str.to_string()),
+            ("repository", config.repository.clone()),
+            ("version", config.version.clone()),
+            ("sw_cli_url", config.sw_cli_url.clone()),
+            ("dependencies", Self::render_dependencies(config)),
+            (
+                "build_dependencies",
+                Self::render_build_dependencies(config),
+            ),
+            ("modules", Self::render_modules(config)),
+        ]
+    }
+
+    /// Modules and setup of `main.rs`: settings, environment, man page and logging.
+    fn main_vars(config: &CliConfig) -> Vars {
+        vec![
+            ("main_modules", Self::render_main_modules(config)),
+            ("cli_imports", Self::render_cli_imports(config)),
+            ("main_completions", Self::render_main_completions(config)),
+            ("main_settings", Self::render_main_settings(config)),
+            ("settings_args", Self::render_settings_args(config, "cli")),
+            ("settings_lints", Self::render_settings_lints(config)),
+            ("settings_help", Self::render_settings_help(config)),
+            ("env_prefix", Self::env_prefix(config)),
+            ("arg_env_vars", Self::render_arg_env_vars(config)),
+            ("clap_features", Self::render_clap_features(config)),
+            ("environment_help", Self::render_environment_help(config)),
+            ("man_environment", Self::render_man_environment(config)),
+            (
+                "man_environment_fn",
+                Self::render_man_environment_fn(config),
+            ),
+            ("man_page_command", Self::render_man_page_command(config)),
+            ("man_examples", Self::render_man_examples(config)),
+            ("man_examples_fn", Self::render_man_examples_fn(config)),
+            ("example_tests", Self::render_example_tests(config)),
+            ("main_imports", Self::render_main_imports(config)),
+            ("main_return", Self::render_main_return(config)),
+            ("main_success", Self::render_main_success(config)),
+            ("parse_cli", Self::render_parse_cli(config)),
+            ("main_run", Self::render_main_run(config)),
+            ("main_logging", Self::render_main_logging(config)),
+            ("log_imports", Self::render_log_imports(config)),
+            ("log_file_param", Self::render_log_file_param(config)),
+            ("log_json", Self::render_log_json(config)),
+            ("log_init", Self::render_log_init(config)),
+        ]
+    }
+
+    /// Error scaffold, the parser the entry points import, member manifests
+    /// and help texts.
+    fn scaffold_vars(config: &CliConfig) -> Vars {
+        vec![
+            ("bin_dependencies", Self::render_bin_dependencies(config)),
+            ("core_dependencies", Self::render_core_dependencies(config)),
+            ("error_lints", Self::render_error_lints(config)),
+            ("exit_variants", Self::render_exit_variants(config)),
+            ("error_variants", Self::render_error_variants(config)),
+            ("error_exits", Self::render_error_exits(config)),
+            ("io_exit", Self::render_io_exit(config)),
+            ("io_exit_check", Self::render_io_exit_check(config)),
+            (
+                "settings_exit_check",
+                Self::render_settings_exit_check(config),
+            ),
+            ("parse_args", Self::render_parse_args(config)),
+            ("exit_codes_help", Self::render_exit_codes_help(config)),
+            ("command_imports", Self::render_command_imports(config)),
+            ("dispatch_imports", Self::render_dispatch_imports(config)),
+            ("result_type", Self::result_type(config).to_string()),
+            ("completions_help", Self::render_completions_help(config)),
+            ("parser_module", Self::parser_path(config).0.to_string()),
+            ("parser_type", Self::parser_path(config).1.to_string()),
+            ("man_about", Self::escape(&config.short_description)),
+            ("man_long_about", Self::escape(&config.long_description)),
+            (
+                "man_dev_dependencies",
+                Self::render_man_dev_dependencies(config),
+            ),
+            (
+                "core_dev_dependencies",
+                Self::render_core_dev_dependencies(config),
+            ),
+            (
+                "member_dependencies",
+                Self::render_member_dependencies(config),
+            ),
+            (
+                "member_build_dependencies",
+                Self::render_member_build_dependencies(config),
+            ),
+            ("version_crate", Self::version_crate(config)),
+            ("short_help", Self::help_text_expr(config, false)),
+            ("long_help", Self::help_text_expr(config, true)),
+        ]
+    }
+
+    /// sw-cli setup, xtask, README and CI files.
+    fn project_vars(config: &CliConfig) -> Vars {
+        vec![
+            ("sw_cli_setup", Self::render_sw_cli_setup(config)),
+            ("sw_cli_update", Self::render_sw_cli_update(config)),
+            ("xtask_workspace", Self::render_xtask_workspace(config)),
+            ("xtask_member", Self::render_xtask_member(config)),
+            (
+                "xtask_default_members",
+                Self::render_xtask_default_members(config),
+            ),
+            ("xtask_setup", Self::render_xtask_setup(config)),
+            ("xtask_update", Self::render_xtask_update(config)),
+            ("xtask_dist", Self::render_xtask_dist(config)),
+            ("dist_extras", Self::render_dist_extras(config)),
+            (
+                "readme_description",
+                Self::render_readme_description(config),
+            ),
+            ("readme_build", Self::render_readme_build(config)),
+            ("readme_usage", Self::render_readme_usage(config)),
+            ("readme_options", Self::render_readme_options(config)),
+            ("readme_commands", Self::render_readme_commands(config)),
+            ("readme_examples", Self::render_readme_examples(config)),
+            ("readme_extras", Self::render_readme_extras(config)),
+            ("release_command", Self::render_release_command(config)),
+            ("release_artifacts", Self::render_release_artifacts(config)),
+            ("github_checkout", Self::render_github_checkout(config)),
+            ("gitlab_variables", Self::render_gitlab_variables(config)),
+            ("make_submodules", Self::render_make_submodules(config)),
+            (
+                "make_prerequisites",
+                Self::render_make_prerequisites(config),
+            ),
+        ]
+    }
+
+    /// The clap parser: global options, arguments, commands and their tests.
+    fn parser_vars(config: &CliConfig) -> Vars {
+        vec![
+            ("global_args", Self::render_global_args(config)),
+            ("global_imports", Self::render_global_imports(config)),
+            ("global_support", Self::render_global_support(config)),
+            ("help_examples", Self::render_help_examples(config)),
+            ("args", Self::render_cli_args(config)),
+            (
+                "arg_groups",
+                Self::render_arg_groups(&config.groups, "")
+                    .trim_end()
+                    .to_string(),
+            ),
+            ("commands", Self::render_commands_enum(config)),
+            ("value_enums", Self::render_value_enums(config)),
+            ("builder_fields", Self::render_builder_fields(config)),
+            ("builder_defaults", Self::render_builder_defaults(config)),
+            ("builder_setters", Self::render_builder_setters(config)),
+            ("builder_build", Self::render_builder_build(config)),
+            ("dispatch_arms", Self::render_dispatch_arms(config)),
+            ("handlers", Self::render_handlers(config)),
+            ("cli_tests", Self::render_cli_tests(config)),
+            (
+                "help_snapshot_tests",
+                Self::render_help_snapshot_tests(config),
+            ),
+            ("command_modules", Self::render_command_modules(config)),
+            ("command_variants", Self::render_command_variants(config)),
+            ("command_runs", Self::render_command_runs(config)),
+        ]
+    }
+
+    /// `[dependencies]` entries of the generated `Cargo.toml` besides clap.
+    fn render_dependencies(config: &CliConfig) -> String {
+        let mut dependencies = Vec::new();
+        if config.completions {
+            dependencies.push("clap_complete = \"4.5\"".to_string());
+        }
+        dependencies.extend(Self::render_logging_dependencies(config));
+        dependencies.extend(Self::render_error_dependency(config));
+        if config.settings {
+            dependencies.push("toml = \"0.8\"".to_string());
+        }
+        dependencies.extend(Self::render_sw_cli_dependency(config));
+        dependencies.join("\n")
+    }
+
+    /// The `[build-dependencies]` section, if the build script needs any.
+    fn render_build_dependencies(config: &CliConfig) -> String {
+        match Self::render_sw_cli_dependency(config) {
+            Some(sw_cli) => format!("\n[build-dependencies]\n{}", sw_cli),
+            None => String::new(),
+        }
+    }
+
+    /// Module declarations and re-exports of the generated `lib.rs`.
+    fn render_modules(config: &CliConfig) -> String {
+        let mut modules = match (config.layout, config.architecture) {
+            (Layout::CommandsDir, _) => vec!["pub mod cli;", "pub mod commands;"],
+            (_, Architecture::Simple) => vec!["pub mod cli;"],
+            (_, Architecture::Dispatch) => vec!["pub mod config;", "pub mod dispatch;"],
+        };
+        if config.error_handling != ErrorHandling::None {
+            modules.push("pub mod error;");
+        }
+        if config.settings {
+            modules.push("pub mod settings;");
+        }
+        // Other layouts declare the version module in main.rs
+        if config.sw_cli_dependency == SwCliDependency::Standalone
+            && matches!(config.layout, Layout::Split | Layout::CommandsDir)
+        {
+            modules.push("pub mod version;");
+            modules.push("\npub use version::check_version_flag;");
+        }
+        modules.join("\n")
+    }
+}
@@ -0,0 +1,182 @@
+use crate::codegen::dependency::SW_CLI_DIR;
+use crate::codegen::generators::{CodeGenerator, FILE_MODE};
+use crate::models::config::{CliConfig, Layout, SwCliDependency};
+
+impl CodeGenerator {
+    /// The `xtask` crate and the cargo alias running it, as (template name,
+    /// output path, mode).
+    pub(crate) fn xtask_outputs(config: &CliConfig) -> Vec<(&'static str, String, u32)> {
+        if !config.xtask {
+            return Vec::new();
+        }
+        vec![
+            (
+                "xtask_Cargo.toml",
+                "xtask/Cargo.toml".to_string(),
+                FILE_MODE,
+            ),
+            ("xtask_main.rs", "xtask/src/main.rs".to_string(), FILE_MODE),
+            (
+                "cargo_config.toml",
+                ".cargo/config.toml".to_string(),
+                FILE_MODE,
+            ),
+        ]
+    }
+
+    /// `[workspace]` section turning the root package into a workspace with
+    /// the `xtask` crate.
+    pub(crate) fn render_xtask_workspace(config: &CliConfig) -> String {
+        if config.xtask && config.layout != Layout::Workspace {
+            "\n[workspace]\nmembers = [\"xtask\"]".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Extra entry of the `members` list of the workspace layout.
+    pub(crate) fn render_xtask_member(config: &CliConfig) -> String {
+        if config.xtask {
+            ", \"xtask\"".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// `default-members` of the workspace layout, so a plain `cargo run`
+    /// still picks the CLI rather than `xtask`.
+    pub(crate) fn render_xtask_default_members(config: &CliConfig) -> String {
+        if config.xtask {
+            format!(
+                "default-members = [\"crates/{name}\", \"crates/{name}-core\"]",
+                name = config.name
+            )
+        } else {
+            String::new()
+        }
+    }
+
+    /// The steps of `cargo xtask setup` that make sw-cli available, the
+    /// counterpart of [`CodeGenerator::render_sw_cli_setup`].
+    pub(crate) fn render_xtask_setup(config: &CliConfig) -> String {
+        match &config.sw_cli_dependency {
+            SwCliDependency::Submodule => format!(
+                r#"
+    if project_root().join("{dir}").exists() {{
+        println!("sw-cli submodule already exists, skipping...");
+    }} else {{
+        println!("Adding sw-cli submodule...");
+        run(
+            "git",
+            &[
+                "submodule",
+                "add",
+                "{url}",
+                "{dir}",
+            ],
+        )?;
+    }}
+    println!("Updating submodules...");
+    run("git", &["submodule", "update", "--init", "--recursive"])?;"#,
+                dir = SW_CLI_DIR,
+                url = config.sw_cli_url
+            ),
+            SwCliDependency::Git { .. } | SwCliDependency::Registry { .. } => {
+                "\n    println!(\"sw-cli will be fetched by cargo on the first build\");"
+                    .to_string()
+            }
+            SwCliDependency::Path { path } => format!(
+                r#"
+    if !project_root().join("{path}/Cargo.toml").exists() {{
+        return Err("sw-cli checkout not found at {path}".into());
+    }}
+    println!("Using sw-cli checkout at {path}");"#,
+                path = path
+            ),
+            SwCliDependency::Vendored { .. } => format!(
+                "\n    println!(\"Using vendored sw-cli in {}\");",
+                SW_CLI_DIR
+            ),
+            SwCliDependency::Standalone => String::new(),
+        }
+    }
+
+    /// The steps of `cargo xtask build` that refresh sw-cli before building.
+    pub(crate) fn render_xtask_update(config: &CliConfig) -> String {
+        match &config.sw_cli_dependency {
+            SwCliDependency::Submodule => r#"    println!("Updating git submodules to latest...");
+    run(
+        "git",
+        &[
+            "submodule",
+            "update",
+            "--init",
+            "--recursive",
+            "--remote",
+            "--merge",
+        ],
+    )?;"#
+                .to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// The steps of `cargo xtask dist` collecting completions and man pages.
+    pub(crate) fn render_xtask_dist(config: &CliConfig) -> String {
+        let mut out = String::new();
+        if config.completions {
+            out.push_str(
+                r#"
+    let completions = dist.join("completions");
+    fs::create_dir_all(&completions)?;
+    for (shell, file) in [
+        ("bash", format!("{}.bash", BIN)),
+        ("zsh", format!("_{}", BIN)),
+        ("fish", format!("{}.fish", BIN)),
+    ] {
+        let output = Command::new(&binary)
+            .args(["completions", shell])
+            .output()?;
+        if !output.status.success() {
+            return Err(format!("generating {} completions failed", shell).into());
+        }
+        fs::write(completions.join(file), output.stdout)?;
+    }
+"#,
+            );
+        }
+        if config.man_pages {
+            out.push_str(&format!(
+                r#"
+    let man = dist.join("man");
+    let man = man.to_string_lossy();
+    run(
+        "cargo",
+        &[
+            "run",
+            "--package",
+            "{}",
+            "--example",
+            "mangen",
+            "--",
+            &man,
+        ],
+    )?;
+"#,
+                Self::man_package(config)
+            ));
+        }
+        out.trim_end().to_string()
+    }
+
+    /// What `cargo xtask dist` collects besides the binary, for its usage text.
+    pub(crate) fn render_dist_extras(config: &CliConfig) -> String {
+        match (config.completions, config.man_pages) {
+            (true, true) => ", completions and man pages",
+            (true, false) => " and completions",
+            (false, true) => " and man pages",
+            (false, false) => "",
+        }
+        .to_string()
+    }
+}
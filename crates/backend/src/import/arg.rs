@@ -0,0 +1,213 @@
+use super::ClapImporter;
+use crate::codegen::generators::CodeGenerator;
+use crate::models::config::{ArgConfig, ArgKind, ValueConfig};
+use quote::ToTokens;
+use syn::meta::ParseNestedMeta;
+use syn::{Expr, ItemEnum};
+
+/// Settings of an `#[arg(...)]` attribute that decide the kind of an
+/// argument and whether it is required.
+#[derive(Default)]
+struct ArgSettings {
+    named: bool,
+    count: bool,
+    has_default: bool,
+    explicit_required: Option<bool>,
+}
+
+impl ClapImporter {
+    pub(super) fn import_arg(name: &str, field: &syn::Field) -> ArgConfig {
+        let mut arg = ArgConfig::new(name);
+        arg.description = Self::doc_comment(&field.attrs);
+
+        let mut settings = ArgSettings::default();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("arg") || attr.path().is_ident("clap"))
+        {
+            // Settings that cannot be read are skipped rather than failing the import
+            let parsed = attr
+                .parse_nested_meta(|meta| Self::arg_setting(meta, name, &mut arg, &mut settings));
+            if let Err(e) = parsed {
+                eprintln!(
+                    "Warning: some settings of argument '{}' could not be read and were skipped: {}",
+                    name, e
+                );
+            }
+        }
+
+        Self::resolve_kind(&mut arg, &field.ty, &settings);
+        arg
+    }
+
+    /// Read one setting of an `#[arg(...)]` attribute into `arg`.
+    fn arg_setting(
+        meta: ParseNestedMeta,
+        name: &str,
+        arg: &mut ArgConfig,
+        settings: &mut ArgSettings,
+    ) -> syn::Result<()> {
+        let has_value = meta.input.peek(syn::Token![=]);
+        let key = meta
+            .path
+            .get_ident()
+            .map(|ident| ident.to_string())
+            .unwrap_or_default();
+        match key.as_str() {
+            "short" | "long" => {
+                settings.named = true;
+                Self::arg_switch(meta, &key, name, arg)?;
+            }
+            "default_value" => {
+                settings.has_default = true;
+                arg.default_value = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            }
+            // Without a value clap uses `Default::default()`, which the
+            // generated field type provides as well
+            "default_value_t" | "default_values_t" => {
+                settings.has_default = true;
+                if has_value {
+                    let expr = meta.value()?.parse::<Expr>()?;
+                    arg.default_value = Some(Self::expr_string(&expr));
+                }
+            }
+            "required" => {
+                settings.explicit_required = Some(if has_value {
+                    meta.value()?.parse::<syn::LitBool>()?.value()
+                } else {
+                    true
+                });
+            }
+            "action" => {
+                let expr = meta.value()?.parse::<Expr>()?;
+                settings.count = expr.to_token_stream().to_string().ends_with("Count");
+            }
+            _ => Self::arg_relation(meta, &key, arg)?,
+        }
+        Ok(())
+    }
+
+    /// Read the `short` or `long` switch of a named argument; clap derives
+    /// them from `name` when no value is given.
+    fn arg_switch(
+        meta: ParseNestedMeta,
+        key: &str,
+        name: &str,
+        arg: &mut ArgConfig,
+    ) -> syn::Result<()> {
+        let has_value = meta.input.peek(syn::Token![=]);
+        if key == "short" {
+            arg.short = if has_value {
+                Some(meta.value()?.parse::<syn::LitChar>()?.value())
+            } else {
+                name.chars().next()
+            };
+        } else if has_value {
+            let long = meta.value()?.parse::<syn::LitStr>()?.value();
+            if long != CodeGenerator::kebab_case(name) {
+                arg.long = Some(long);
+            }
+        }
+        Ok(())
+    }
+
+    /// Read a setting that does not affect the kind of the argument: its
+    /// value name, environment variable and relations to other arguments.
+    fn arg_relation(meta: ParseNestedMeta, key: &str, arg: &mut ArgConfig) -> syn::Result<()> {
+        let has_value = meta.input.peek(syn::Token![=]);
+        match key {
+            "value_name" => {
+                arg.value_name = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            }
+            // The generator derives the variable name from the CLI name
+            "env" => {
+                arg.env = true;
+                if has_value {
+                    meta.value()?.parse::<Expr>()?;
+                }
+            }
+            "conflicts_with" | "conflicts_with_all" => {
+                let expr = meta.value()?.parse::<Expr>()?;
+                arg.conflicts_with.extend(Self::expr_strings(&expr));
+            }
+            "requires" => {
+                let expr = meta.value()?.parse::<Expr>()?;
+                arg.requires.extend(Self::expr_strings(&expr));
+            }
+            "required_unless_present" | "required_unless_present_any" => {
+                let expr = meta.value()?.parse::<Expr>()?;
+                arg.required_unless_present
+                    .extend(Self::expr_strings(&expr));
+            }
+            // Other settings are not part of the config model
+            _ if has_value => {
+                meta.value()?.parse::<Expr>()?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Derive the value type, kind and `required` of `arg` from the field
+    /// type `ty` and the settings of its attributes.
+    fn resolve_kind(arg: &mut ArgConfig, ty: &syn::Type, settings: &ArgSettings) {
+        let inner = Self::unwrap_type(ty, "Option");
+        let optional = !std::ptr::eq(inner, ty);
+        let element = Self::unwrap_type(inner, "Vec");
+        arg.multiple = !std::ptr::eq(element, inner);
+        arg.value_type = element.to_token_stream().to_string().replace(' ', "");
+
+        arg.kind = if settings.count {
+            ArgKind::Count
+        } else if arg.value_type == "bool" && !arg.multiple {
+            ArgKind::Flag
+        } else if settings.named {
+            ArgKind::Option
+        } else {
+            ArgKind::Positional
+        };
+        if matches!(arg.kind, ArgKind::Flag | ArgKind::Count) {
+            arg.value_type = "String".to_string();
+        }
+        arg.required = settings.explicit_required.unwrap_or(
+            matches!(arg.kind, ArgKind::Option | ArgKind::Positional)
+                && !optional
+                && !arg.multiple
+                && !settings.has_default,
+        );
+    }
+
+    /// The values of a `#[derive(ValueEnum)]` enum, minus `#[value(skip)]`
+    /// variants.
+    pub(super) fn import_values(value_enum: &ItemEnum) -> Vec<ValueConfig> {
+        let mut values = Vec::new();
+        for variant in &value_enum.variants {
+            let mut name = CodeGenerator::kebab_case(&variant.ident.to_string());
+            let mut skip = false;
+            for attr in variant
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("value") || attr.path().is_ident("clap"))
+            {
+                let _ = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("name") {
+                        name = meta.value()?.parse::<syn::LitStr>()?.value();
+                    } else if meta.path.is_ident("skip") {
+                        skip = true;
+                    } else if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<Expr>()?;
+                    }
+                    Ok(())
+                });
+            }
+            if !skip {
+                values.push(ValueConfig {
+                    name,
+                    description: Self::doc_comment(&variant.attrs),
+                });
+            }
+        }
+        values
+    }
+}
@@ -0,0 +1,140 @@
+use super::{ClapImporter, CommandAttrs};
+use quote::ToTokens;
+use syn::{Attribute, Expr, GenericArgument, Lit, PathArguments, Type};
+
+impl ClapImporter {
+    pub(super) fn derives(attrs: &[Attribute], name: &str) -> bool {
+        attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("derive"))
+            .any(|attr| {
+                let mut found = false;
+                let _ = attr.parse_nested_meta(|meta| {
+                    found |= meta
+                        .path
+                        .segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == name);
+                    Ok(())
+                });
+                found
+            })
+    }
+
+    /// Whether a `#[command(..)]`, `#[arg(..)]` or `#[clap(..)]` attribute
+    /// contains the bare setting `flag`, e.g. `subcommand` or `flatten`.
+    pub(super) fn has_flag(attrs: &[Attribute], flag: &str) -> bool {
+        attrs
+            .iter()
+            .filter(|attr| {
+                attr.path().is_ident("command")
+                    || attr.path().is_ident("arg")
+                    || attr.path().is_ident("clap")
+            })
+            .any(|attr| {
+                let mut found = false;
+                let _ = attr.parse_nested_meta(|meta| {
+                    found |= meta.path.is_ident(flag);
+                    if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<Expr>()?;
+                    }
+                    Ok(())
+                });
+                found
+            })
+    }
+
+    pub(super) fn command_attrs(attrs: &[Attribute]) -> CommandAttrs {
+        let mut command = CommandAttrs::default();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("command") || attr.path().is_ident("clap"))
+        {
+            let _ = attr.parse_nested_meta(|meta| {
+                if !meta.input.peek(syn::Token![=]) {
+                    return Ok(());
+                }
+                let expr = meta.value()?.parse::<Expr>()?;
+                // Only string literals can be imported; macros like
+                // `sw_cli::short_help!()` are regenerated by the templates
+                let Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(value),
+                    ..
+                }) = expr
+                else {
+                    return Ok(());
+                };
+                if meta.path.is_ident("name") {
+                    command.name = Some(value.value());
+                } else if meta.path.is_ident("about") {
+                    command.about = Some(value.value());
+                } else if meta.path.is_ident("long_about") {
+                    command.long_about = Some(value.value());
+                }
+                Ok(())
+            });
+        }
+        command
+    }
+
+    pub(super) fn doc_comment(attrs: &[Attribute]) -> String {
+        attrs
+            .iter()
+            .filter_map(|attr| match &attr.meta {
+                syn::Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(doc), ..
+                    }) => Some(doc.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim()
+            .to_string()
+    }
+
+    /// `Option<T>` -> `T` when `wrapper` is `Option`; other types unchanged.
+    pub(super) fn unwrap_type<'a>(ty: &'a Type, wrapper: &str) -> &'a Type {
+        if let Type::Path(path) = ty
+            && let Some(segment) = path.path.segments.last()
+            && segment.ident == wrapper
+            && let PathArguments::AngleBracketed(args) = &segment.arguments
+            && let Some(GenericArgument::Type(inner)) = args.args.first()
+        {
+            return inner;
+        }
+        ty
+    }
+
+    pub(super) fn type_name(ty: &Type) -> String {
+        match ty {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .map(|segment| segment.ident.to_string())
+                .unwrap_or_default(),
+            _ => String::new(),
+        }
+    }
+
+    pub(super) fn expr_string(expr: &Expr) -> String {
+        match expr {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Str(value),
+                ..
+            }) => value.value(),
+            other => other.to_token_stream().to_string().replace(' ', ""),
+        }
+    }
+
+    /// Argument ids of `"a"` or `["a", "b"]`.
+    pub(super) fn expr_strings(expr: &Expr) -> Vec<String> {
+        match expr {
+            Expr::Array(array) => array.elems.iter().map(Self::expr_string).collect(),
+            other => vec![Self::expr_string(other)],
+        }
+    }
+}
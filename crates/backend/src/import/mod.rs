@@ -0,0 +1,317 @@
+mod arg;
+mod attrs;
+
+use crate::codegen::generators::CodeGenerator;
+use crate::models::config::{
+    ArgConfig, ArgKind, CliConfig, CommandConfig, GlobalOption, LicenseType,
+};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use syn::{Fields, Item, ItemEnum, ItemStruct};
+
+/// Builds a `CliConfig` from an existing crate that defines its CLI with clap derive.
+pub struct ClapImporter;
+
+/// Clap derive items found in a crate's sources, indexed by type name.
+#[derive(Default)]
+struct ClapItems {
+    parsers: Vec<ItemStruct>,
+    subcommands: HashMap<String, ItemEnum>,
+    args: HashMap<String, ItemStruct>,
+    value_enums: HashMap<String, ItemEnum>,
+}
+
+/// Settings read from a `#[command(...)]` or `#[clap(...)]` attribute.
+#[derive(Default)]
+struct CommandAttrs {
+    name: Option<String>,
+    about: Option<String>,
+    long_about: Option<String>,
+}
+
+impl ClapImporter {
+    /// Read `Cargo.toml`, `COPYRIGHT` and the clap derive structs under `src/`
+    /// of the crate at `project_dir`.
+    pub fn import(project_dir: &str) -> Result<CliConfig, Box<dyn std::error::Error>> {
+        let root = Path::new(project_dir);
+        let mut config = Self::import_manifest(root)?;
+
+        if let Ok(copyright) = fs::read_to_string(root.join("COPYRIGHT")) {
+            config.copyright = copyright.trim().to_string();
+        }
+
+        let items = Self::collect_items(&root.join("src"))?;
+        let parser = items
+            .parsers
+            .iter()
+            .find(|item| item.ident == "Cli")
+            .or_else(|| items.parsers.first())
+            .ok_or_else(|| format!("No #[derive(Parser)] struct found in {}/src", project_dir))?;
+
+        let attrs = Self::command_attrs(&parser.attrs);
+        let docs = Self::doc_comment(&parser.attrs);
+        if let Some(name) = attrs.name {
+            config.name = name;
+        }
+        if let Some(about) = attrs
+            .about
+            .or_else(|| docs.lines().next().map(str::to_string))
+        {
+            config.short_description = about;
+        }
+        if let Some(long_about) = attrs.long_about.or((!docs.is_empty()).then_some(docs)) {
+            config.long_description = long_about;
+        }
+
+        let (args, commands) = Self::import_fields(&parser.fields, &items)?;
+        // Fields matching a global option are rendered from `global_options`
+        let (globals, args): (Vec<_>, Vec<_>) = args
+            .into_iter()
+            .partition(|arg| Self::global_option(arg).is_some());
+        config.global_options = globals.iter().filter_map(Self::global_option).collect();
+        config.args = args;
+        config.commands = commands;
+
+        Ok(config)
+    }
+
+    fn import_manifest(root: &Path) -> Result<CliConfig, Box<dyn std::error::Error>> {
+        let manifest_path = root.join("Cargo.toml");
+        let content = fs::read_to_string(&manifest_path)
+            .map_err(|e| format!("Failed to read {}: {}", manifest_path.display(), e))?;
+        let manifest: toml::Value = toml::from_str(&content)?;
+        let package = manifest
+            .get("package")
+            .ok_or_else(|| format!("No [package] section in {}", manifest_path.display()))?;
+
+        // Fields inherited from a workspace (`version.workspace = true`) are not strings
+        let field = |key: &str| package.get(key).and_then(|value| value.as_str());
+
+        let mut config = CliConfig::default();
+        if let Some(name) = field("name") {
+            config.name = name.to_string();
+            config.repository = format!("https://github.com/yourusername/{}", name);
+        }
+        if let Some(version) = field("version") {
+            config.version = version.to_string();
+        }
+        if let Some(description) = field("description") {
+            config.short_description = description.to_string();
+            config.long_description = description.to_string();
+        }
+        if let Some(author) = package
+            .get("authors")
+            .and_then(|authors| authors.as_array())
+            .and_then(|authors| authors.first())
+            .and_then(|author| author.as_str())
+        {
+            config.author = author.to_string();
+        }
+        if let Some(license) = field("license") {
+            config.license = match license {
+                "MIT" => LicenseType::MIT,
+                "Apache-2.0" => LicenseType::Apache2,
+                "GPL-3.0" | "GPL-3.0-only" | "GPL-3.0-or-later" => LicenseType::GPL3,
+                other => LicenseType::Custom(other.to_string()),
+            };
+        }
+        if let Some(repository) = field("repository") {
+            config.repository = repository.to_string();
+        }
+
+        Ok(config)
+    }
+
+    fn collect_items(src_dir: &Path) -> Result<ClapItems, Box<dyn std::error::Error>> {
+        let mut items = ClapItems::default();
+        let mut pending = vec![src_dir.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            for entry in fs::read_dir(&dir)
+                .map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?
+            {
+                let path = entry?.path();
+                if path.is_dir() {
+                    pending.push(path);
+                } else if path.extension().is_some_and(|ext| ext == "rs") {
+                    let source = fs::read_to_string(&path)?;
+                    let file = syn::parse_file(&source)
+                        .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+                    Self::collect_from(file.items, &mut items);
+                }
+            }
+        }
+        Ok(items)
+    }
+
+    fn collect_from(source_items: Vec<Item>, items: &mut ClapItems) {
+        for item in source_items {
+            match item {
+                Item::Struct(item) if Self::derives(&item.attrs, "Parser") => {
+                    items.parsers.push(item)
+                }
+                Item::Struct(item) if Self::derives(&item.attrs, "Args") => {
+                    items.args.insert(item.ident.to_string(), item);
+                }
+                Item::Enum(item) if Self::derives(&item.attrs, "Subcommand") => {
+                    items.subcommands.insert(item.ident.to_string(), item);
+                }
+                Item::Enum(item) if Self::derives(&item.attrs, "ValueEnum") => {
+                    items.value_enums.insert(item.ident.to_string(), item);
+                }
+                Item::Mod(module) => {
+                    if let Some((_, content)) = module.content {
+                        Self::collect_from(content, items);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Split the fields of a parser, args struct or variant into arguments and
+    /// the subcommands referenced by a `#[command(subcommand)]` field.
+    fn import_fields(
+        fields: &Fields,
+        items: &ClapItems,
+    ) -> Result<(Vec<ArgConfig>, Vec<CommandConfig>), Box<dyn std::error::Error>> {
+        let mut args = Vec::new();
+        let mut commands = Vec::new();
+
+        for field in fields {
+            let Some(ident) = &field.ident else {
+                continue;
+            };
+            let type_name = Self::type_name(Self::unwrap_type(&field.ty, "Option"));
+
+            if Self::has_flag(&field.attrs, "subcommand") {
+                let subcommands = items
+                    .subcommands
+                    .get(&type_name)
+                    .ok_or_else(|| format!("No #[derive(Subcommand)] enum named {}", type_name))?;
+                for variant in &subcommands.variants {
+                    commands.push(Self::import_variant(variant, items)?);
+                }
+            } else if Self::has_flag(&field.attrs, "flatten") {
+                let flattened = items
+                    .args
+                    .get(&type_name)
+                    .ok_or_else(|| format!("No #[derive(Args)] struct named {}", type_name))?;
+                let (flattened_args, flattened_commands) =
+                    Self::import_fields(&flattened.fields, items)?;
+                args.extend(flattened_args);
+                commands.extend(flattened_commands);
+            } else {
+                args.push(Self::import_field(&ident.to_string(), field, items));
+            }
+        }
+
+        Ok((args, commands))
+    }
+
+    /// The argument of a plain field named `ident`.
+    fn import_field(ident: &str, field: &syn::Field, items: &ClapItems) -> ArgConfig {
+        // `r#type` becomes `type_`, keeping clap's `--type` and `TYPE`
+        let raw = ident.strip_prefix("r#");
+        let name = raw.map_or(ident.to_string(), |raw| format!("{}_", raw));
+        let mut arg = Self::import_arg(&name, field);
+        if let Some(raw) = raw {
+            if arg.kind == ArgKind::Positional {
+                arg.value_name.get_or_insert_with(|| raw.to_uppercase());
+            } else {
+                arg.long
+                    .get_or_insert_with(|| CodeGenerator::kebab_case(raw));
+            }
+        }
+        if let Some(value_enum) = items.value_enums.get(&arg.value_type) {
+            arg.values = Self::import_values(value_enum);
+            arg.value_type = "String".to_string();
+        }
+        arg
+    }
+
+    fn import_variant(
+        variant: &syn::Variant,
+        items: &ClapItems,
+    ) -> Result<CommandConfig, Box<dyn std::error::Error>> {
+        let attrs = Self::command_attrs(&variant.attrs);
+        let docs = Self::doc_comment(&variant.attrs);
+        let mut command = CommandConfig {
+            name: attrs
+                .name
+                .unwrap_or_else(|| CodeGenerator::kebab_case(&variant.ident.to_string())),
+            description: attrs.about.unwrap_or(docs),
+            args: Vec::new(),
+            groups: Vec::new(),
+            examples: Vec::new(),
+        };
+
+        let (args, nested) = Self::variant_args(&variant.fields, items)?;
+        command.args = args;
+        if nested {
+            eprintln!(
+                "Warning: nested subcommands of '{}' are not supported and were skipped",
+                command.name
+            );
+        }
+
+        Ok(command)
+    }
+
+    /// The arguments of a subcommand variant, and whether it has nested
+    /// subcommands.
+    fn variant_args(
+        fields: &Fields,
+        items: &ClapItems,
+    ) -> Result<(Vec<ArgConfig>, bool), Box<dyn std::error::Error>> {
+        Ok(match fields {
+            Fields::Named(_) => {
+                let (args, commands) = Self::import_fields(fields, items)?;
+                (args, !commands.is_empty())
+            }
+            // `Variant(VariantArgs)` with a separate #[derive(Args)] struct, or
+            // `Variant(NestedCommands)` wrapping another subcommand enum
+            Fields::Unnamed(fields) => {
+                let type_name = fields
+                    .unnamed
+                    .first()
+                    .map(|field| Self::type_name(&field.ty))
+                    .unwrap_or_default();
+                match items.args.get(&type_name) {
+                    Some(args_struct) => {
+                        let (args, commands) = Self::import_fields(&args_struct.fields, items)?;
+                        (args, !commands.is_empty())
+                    }
+                    None if items.subcommands.contains_key(&type_name) => (Vec::new(), true),
+                    None => {
+                        return Err(format!("No #[derive(Args)] struct named {}", type_name).into());
+                    }
+                }
+            }
+            Fields::Unit => (Vec::new(), false),
+        })
+    }
+
+    /// The global option a top-level argument was generated from, matched by
+    /// field name and kind.
+    fn global_option(arg: &ArgConfig) -> Option<GlobalOption> {
+        let value_names: Vec<&str> = arg.values.iter().map(|value| value.name.as_str()).collect();
+        match (arg.name.as_str(), arg.kind) {
+            ("verbose", ArgKind::Flag) => Some(GlobalOption::Verbose),
+            ("verbose" | "verbosity", ArgKind::Count) => Some(GlobalOption::Verbosity),
+            ("dry_run", ArgKind::Flag) => Some(GlobalOption::DryRun),
+            ("quiet", ArgKind::Flag) => Some(GlobalOption::Quiet),
+            ("no_input", ArgKind::Flag) => Some(GlobalOption::NoInput),
+            ("color", ArgKind::Option) if arg.value_type.ends_with("ColorChoice") => {
+                Some(GlobalOption::Color)
+            }
+            ("config", ArgKind::Option) if arg.value_type.ends_with("PathBuf") => {
+                Some(GlobalOption::ConfigFile)
+            }
+            ("output", ArgKind::Option) if value_names == ["text", "json"] => {
+                Some(GlobalOption::Output)
+            }
+            _ => None,
+        }
+    }
+}
@@ -1,2 +1,5 @@
 pub mod codegen;
+pub mod import;
+pub mod migrate;
 pub mod models;
+pub mod schema;
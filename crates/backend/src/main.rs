@@ -5,7 +5,10 @@ use axum::{
     routing::{get, post},
 };
 use clap::Parser;
-use cli_codegen_backend::{codegen::generators::CodeGenerator, models::config::CliConfig};
+use cli_codegen_backend::{
+    codegen::generators::CodeGenerator, migrate::ConfigMigrator, models::config::CliConfig,
+    schema::ConfigSchema,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tokio::net::TcpListener;
@@ -56,6 +59,7 @@ async fn start_web_server(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         .route("/api/config", get(get_config).post(update_config))
         .route("/api/generate", post(api_generate))
         .route("/api/build-info", get(get_build_info))
+        .route("/api/schema", get(get_schema))
         // Serve static assets (favicon, etc.) from static directory with no-cache headers
         .nest_service("/static", ServeDir::new("crates/backend/static"))
         .layer(SetResponseHeaderLayer::if_not_present(
@@ -86,14 +90,18 @@ async fn get_config(State(state): State<AppState>) -> Json<CliConfig> {
 
 async fn update_config(
     State(_state): State<AppState>,
-    Json(config): Json<CliConfig>,
+    Json(document): Json<Value>,
 ) -> Result<Json<CliConfig>, StatusCode> {
+    let config = ConfigMigrator::from_value(document).map_err(|_| StatusCode::BAD_REQUEST)?;
     // In a real app, we would update the state here
     // For now, we'll just return the config as is
     Ok(Json(config))
 }
 
-async fn api_generate(Json(config): Json<CliConfig>) -> Result<Json<Value>, StatusCode> {
+async fn api_generate(Json(document): Json<Value>) -> Result<Json<Value>, StatusCode> {
+    // Upgrade configurations saved by older versions
+    let config = ConfigMigrator::from_value(document).map_err(|_| StatusCode::BAD_REQUEST)?;
+
     // Validate the configuration
     if let Err(_e) = CodeGenerator::validate_config(&config) {
         return Err(StatusCode::BAD_REQUEST);
@@ -120,6 +128,10 @@ async fn api_generate(Json(config): Json<CliConfig>) -> Result<Json<Value>, Stat
     })))
 }
 
+async fn get_schema() -> Json<Value> {
+    Json(ConfigSchema::schema())
+}
+
 async fn get_build_info() -> Json<BuildInfo> {
     Json(BuildInfo {
         commit_sha: option_env!("BUILD_COMMIT_SHA")
@@ -0,0 +1,103 @@
+use crate::models::config::{CONFIG_SCHEMA_VERSION, CliConfig};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::error::Error;
+
+/// Upgrades configuration documents written by older cli-gen builds to the
+/// current format before they are deserialized.
+pub struct ConfigMigrator;
+
+/// Rewrites a document of one schema version into the next one.
+type Migration = fn(&mut Map<String, Value>) -> Result<(), String>;
+
+/// `MIGRATIONS[n]` upgrades a document from version `n` to `n + 1`.
+const MIGRATIONS: [Migration; CONFIG_SCHEMA_VERSION as usize] = [version_0_to_1];
+
+/// Version 0 covers every document written before the version was recorded.
+/// All fields added up to then have serde defaults, so such documents only
+/// need the version stamped.
+fn version_0_to_1(_document: &mut Map<String, Value>) -> Result<(), String> {
+    Ok(())
+}
+
+impl ConfigMigrator {
+    /// Schema version of a configuration document; 0 when it has none.
+    pub fn document_version(document: &Value) -> Result<u32, String> {
+        let version = match document.get("schema_version") {
+            None => return Ok(0),
+            Some(version) => version
+                .as_u64()
+                .and_then(|version| u32::try_from(version).ok())
+                .ok_or_else(|| {
+                    format!(
+                        "schema_version must be a non-negative integer, found {}",
+                        version
+                    )
+                })?,
+        };
+        if version > CONFIG_SCHEMA_VERSION {
+            return Err(format!(
+                "Configuration schema version {} is newer than the latest supported \
+                 version {}; upgrade cli-gen to read it",
+                version, CONFIG_SCHEMA_VERSION
+            ));
+        }
+        Ok(version)
+    }
+
+    /// Apply every migration from the version of `document` up to the
+    /// current one.
+    pub fn migrate(mut document: Value) -> Result<Value, String> {
+        let version = Self::document_version(&document)?;
+        let object = document
+            .as_object_mut()
+            .ok_or("A configuration must be a JSON object")?;
+        for (from, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+            migration(object).map_err(|e| {
+                format!(
+                    "Failed to migrate from schema version {} to {}: {}",
+                    from,
+                    from + 1,
+                    e
+                )
+            })?;
+            object.insert("schema_version".to_string(), Value::from(from + 1));
+        }
+        Ok(document)
+    }
+
+    /// Deserialize a configuration document of any supported version.
+    pub fn from_value(document: Value) -> Result<CliConfig, Box<dyn Error>> {
+        Ok(serde_json::from_value(Self::migrate(document)?)?)
+    }
+
+    /// Parse a JSON configuration of any supported version.
+    pub fn from_json(source: &str) -> Result<CliConfig, Box<dyn Error>> {
+        Self::from_value(serde_json::from_str(source)?)
+    }
+
+    /// `deserialize_with` helper for configurations embedded in other
+    /// documents, such as the project manifest.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<CliConfig, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let document = Value::deserialize(deserializer)?;
+        Self::from_value(document).map_err(serde::de::Error::custom)
+    }
+
+    /// Upgrade the configuration file at `path` in place. Returns the
+    /// version it was at; files already at the current version are left
+    /// untouched.
+    pub fn migrate_file(path: &str) -> Result<u32, Box<dyn Error>> {
+        let source = std::fs::read_to_string(path)?;
+        let version = Self::document_version(&serde_json::from_str(&source)?)?;
+        if version == CONFIG_SCHEMA_VERSION {
+            return Ok(version);
+        }
+
+        let config = Self::from_json(&source)?;
+        std::fs::write(path, serde_json::to_string_pretty(&config)? + "\n")?;
+        Ok(version)
+    }
+}
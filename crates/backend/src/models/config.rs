@@ -1,8 +1,17 @@
 use chrono::{self, Datelike};
 use serde::{Deserialize, Serialize};
 
+/// Version of the configuration format written by this build. Bump it with
+/// every change older documents cannot be read with, and add the matching
+/// step to `migrate::MIGRATIONS`.
+pub const CONFIG_SCHEMA_VERSION: u32 = 1;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CliConfig {
+    /// Version of the configuration format; documents written before it was
+    /// recorded are version 0 and are upgraded when loaded
+    #[serde(default)]
+    pub schema_version: u32,
     pub name: String,
     pub short_description: String,
     pub long_description: String,
@@ -12,8 +21,331 @@ pub struct CliConfig {
     pub repository: String,
     pub version: String,
     pub sw_cli_url: String,
+    /// How the generated project depends on sw-cli
+    #[serde(default)]
+    pub sw_cli_dependency: SwCliDependency,
     pub version_support: bool,
     pub help_support: bool,
+    /// Module structure of the generated code
+    #[serde(default)]
+    pub architecture: Architecture,
+    /// How the generated sources are split into files and crates
+    #[serde(default)]
+    pub layout: Layout,
+    /// Emit golden-file snapshot tests of every command's `--help` output
+    #[serde(default)]
+    pub snapshot_tests: bool,
+    /// Emit unit tests checking that every example command line parses
+    #[serde(default)]
+    pub example_tests: bool,
+    /// Add a `completions <shell>` subcommand printing shell completions
+    #[serde(default)]
+    pub completions: bool,
+    /// Add `examples/mangen.rs` rendering man pages with clap_mangen
+    #[serde(default)]
+    pub man_pages: bool,
+    /// Emit an `xtask` crate with the release tasks instead of shell scripts
+    #[serde(default)]
+    pub xtask: bool,
+    /// Logging backend wired to the verbosity options
+    #[serde(default)]
+    pub logging: Logging,
+    /// Emit logs as JSON lines (tracing backend)
+    #[serde(default)]
+    pub log_json: bool,
+    /// Add a `--log-file <FILE>` option writing logs to a file
+    #[serde(default)]
+    pub log_file: bool,
+    /// Error type and exit-code mapping of the generated CLI
+    #[serde(default)]
+    pub error_handling: ErrorHandling,
+    /// Include the sysexits(3) codes in the exit-code table and exit with
+    /// `EX_USAGE` (64) on usage errors
+    #[serde(default)]
+    pub sysexits: bool,
+    /// Named exit codes besides 0 (success), 1 (failure) and the usage error
+    #[serde(default)]
+    pub exit_codes: Vec<ExitCodeConfig>,
+    /// Add a layered TOML settings loader and a `config` subcommand
+    /// printing the effective settings
+    #[serde(default)]
+    pub settings: bool,
+    /// CI configurations to emit with the project
+    #[serde(default)]
+    pub ci: Vec<CiTarget>,
+    /// Standard global options of the generated parser
+    #[serde(default = "default_global_options")]
+    pub global_options: Vec<GlobalOption>,
+    /// Top-level arguments, in addition to the global options
+    #[serde(default)]
+    pub args: Vec<ArgConfig>,
+    /// Groups of top-level arguments
+    #[serde(default)]
+    pub groups: Vec<ArgGroupConfig>,
+    /// Example invocations, listed under EXAMPLES in the long help
+    #[serde(default)]
+    pub examples: Vec<ExampleConfig>,
+    /// Subcommands of the CLI
+    #[serde(default)]
+    pub commands: Vec<CommandConfig>,
+}
+
+/// How the generated project pulls in sw-cli.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SwCliDependency {
+    /// Git submodule at `lib/sw-cli` cloned from `sw_cli_url`
+    #[default]
+    Submodule,
+    /// Cargo git dependency on `sw_cli_url`, optionally pinned to a revision or tag
+    Git {
+        #[serde(default)]
+        rev: Option<String>,
+        #[serde(default)]
+        tag: Option<String>,
+    },
+    /// Path dependency on an existing sw-cli checkout, relative to the project
+    Path { path: String },
+    /// Version requirement resolved from the crates.io registry
+    Registry { version: String },
+    /// Sources copied from a local sw-cli checkout into `lib/sw-cli`
+    Vendored { source: String },
+    /// No sw-cli dependency: build metadata and version handling are
+    /// generated into `build.rs` and a local `version` module instead
+    Standalone,
+}
+
+/// Module structure of the generated code.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Architecture {
+    /// `cli.rs` with the parser; command handling is left to `main.rs`
+    #[default]
+    Simple,
+    /// `config.rs` with the parser and a builder, and `dispatch.rs` routing
+    /// each command to its own `CommandHandler`
+    Dispatch,
+}
+
+/// File and crate structure of the generated project.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Everything in `src/main.rs`
+    SingleFile,
+    /// Parser in `cli.rs` behind `lib.rs`, with a thin `main.rs`
+    #[default]
+    Split,
+    /// Like `Split`, with one module per subcommand under `src/commands/`
+    CommandsDir,
+    /// A `<name>-core` library crate and a thin `<name>` binary crate
+    Workspace,
+}
+
+/// A standard option of the generated parser, with its runtime support.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GlobalOption {
+    /// `-v/--verbose` switch
+    Verbose,
+    /// `-n/--dry-run` switch
+    DryRun,
+    /// `-q/--quiet` switch
+    Quiet,
+    /// `-v/--verbose` counted into a level: `-v`, `-vv`, `-vvv`
+    Verbosity,
+    /// `--color auto|always|never`, honoring `NO_COLOR` and terminal detection
+    Color,
+    /// `--config <FILE>`
+    ConfigFile,
+    /// `--output text|json`
+    Output,
+    /// `--no-input`, for running without prompts
+    NoInput,
+}
+
+/// The global options of projects configured before they were selectable.
+fn default_global_options() -> Vec<GlobalOption> {
+    vec![GlobalOption::Verbose, GlobalOption::DryRun]
+}
+
+/// Logging setup of the generated binary.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Logging {
+    /// No logging scaffold
+    #[default]
+    None,
+    /// `tracing` with a `tracing-subscriber` fmt subscriber
+    Tracing,
+    /// `log` with `env_logger`
+    EnvLogger,
+}
+
+/// Error handling scaffold of the generated CLI.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorHandling {
+    /// `Box<dyn Error>` results and exit code 1 on any error
+    #[default]
+    None,
+    /// An `Error` enum deriving `thiserror::Error`, one variant per exit code
+    Thiserror,
+    /// `anyhow::Result`, with an `ExitError` carrying the exit code
+    Anyhow,
+}
+
+/// A named exit code of the generated CLI.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExitCodeConfig {
+    /// Name of the exit code (kebab-case), e.g. `not-found`
+    pub name: String,
+    pub code: u8,
+    /// Meaning of the exit code, listed under EXIT CODES in `--help`
+    #[serde(default)]
+    pub description: String,
+}
+
+/// A CI system the generated project gets a configuration for.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CiTarget {
+    /// `.github/workflows/ci.yml`
+    GitHubActions,
+    /// `.gitlab-ci.yml`
+    GitLab,
+    /// `Makefile` with the same targets, for other CI systems
+    Makefile,
+}
+
+/// A subcommand of the generated CLI.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CommandConfig {
+    /// Command name as typed on the command line (kebab-case)
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub args: Vec<ArgConfig>,
+    #[serde(default)]
+    pub groups: Vec<ArgGroupConfig>,
+    /// Example invocations, shown after the command's `--help`
+    #[serde(default)]
+    pub examples: Vec<ExampleConfig>,
+}
+
+/// An example invocation of the CLI or of one of its commands.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExampleConfig {
+    /// Command line after the CLI name, e.g. `add "Buy milk" --priority 1`
+    pub command_line: String,
+    /// What the example does
+    #[serde(default)]
+    pub description: String,
+}
+
+/// An argument of the CLI or of one of its subcommands.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ArgConfig {
+    /// Field name in the generated struct (snake_case)
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub kind: ArgKind,
+    #[serde(default)]
+    pub short: Option<char>,
+    /// Long flag without the leading dashes; ignored for positional arguments
+    #[serde(default)]
+    pub long: Option<String>,
+    /// Rust type of a single value, e.g. `String`, `PathBuf` or `u32`
+    #[serde(default = "default_value_type")]
+    pub value_type: String,
+    #[serde(default)]
+    pub value_name: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    /// Accept the argument more than once, collecting values into a `Vec`
+    #[serde(default)]
+    pub multiple: bool,
+    #[serde(default)]
+    pub default_value: Option<String>,
+    /// Fall back to the `<NAME>_<ARG>` environment variable (`<NAME>_<COMMAND>_<ARG>`
+    /// for command arguments), e.g. `MY_CLI_OUTPUT`
+    #[serde(default)]
+    pub env: bool,
+    /// Arguments or groups that cannot be used together with this one
+    #[serde(default)]
+    pub conflicts_with: Vec<String>,
+    /// Arguments or groups that must be present when this one is
+    #[serde(default)]
+    pub requires: Vec<String>,
+    /// Require the argument unless any of these arguments or groups is present
+    #[serde(default)]
+    pub required_unless_present: Vec<String>,
+    /// Fixed set of accepted values, generated as a `clap::ValueEnum` enum
+    /// used as the argument's type instead of `value_type`
+    #[serde(default)]
+    pub values: Vec<ValueConfig>,
+}
+
+/// One of the fixed values of an argument, e.g. `json` of `--format`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ValueConfig {
+    /// Value as typed on the command line (kebab-case)
+    pub name: String,
+    /// Help shown next to the value in `--help`
+    #[serde(default)]
+    pub description: String,
+}
+
+/// A named set of arguments of the CLI or of one command, rendered as a clap
+/// `ArgGroup`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ArgGroupConfig {
+    /// Group name (snake_case), usable in `conflicts_with` and `requires`
+    pub name: String,
+    /// Names of the arguments in the group
+    pub args: Vec<String>,
+    /// One of the arguments must be present
+    #[serde(default)]
+    pub required: bool,
+    /// Allow more than one of the arguments at a time
+    #[serde(default)]
+    pub multiple: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ArgKind {
+    /// Boolean switch such as `--force`
+    Flag,
+    /// Switch counted by occurrence such as `-vvv`
+    Count,
+    /// Named option taking a value such as `--output <FILE>`
+    #[default]
+    Option,
+    /// Positional value
+    Positional,
+}
+
+fn default_value_type() -> String {
+    "String".to_string()
+}
+
+impl ArgConfig {
+    /// A named option taking a `String` value.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            description: String::new(),
+            kind: ArgKind::Option,
+            short: None,
+            long: None,
+            value_type: default_value_type(),
+            value_name: None,
+            required: false,
+            multiple: false,
+            default_value: None,
+            env: false,
+            conflicts_with: Vec::new(),
+            requires: Vec::new(),
+            required_unless_present: Vec::new(),
+            values: Vec::new(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -27,6 +359,7 @@ pub enum LicenseType {
 impl Default for CliConfig {
     fn default() -> Self {
         Self {
+            schema_version: CONFIG_SCHEMA_VERSION,
             name: "my-cli".to_string(),
             short_description: "A brief description of the CLI".to_string(),
             long_description: "A longer description of what this CLI does".to_string(),
@@ -37,8 +370,29 @@ impl Default for CliConfig {
             repository: "https://github.com/yourusername/my-cli".to_string(),
             version: "0.1.0".to_string(),
             sw_cli_url: "https://github.com/softwarewrighter/sw-cli.git".to_string(),
+            sw_cli_dependency: SwCliDependency::default(),
             version_support: true,
             help_support: true,
+            architecture: Architecture::default(),
+            layout: Layout::default(),
+            snapshot_tests: false,
+            example_tests: false,
+            completions: false,
+            man_pages: false,
+            xtask: false,
+            logging: Logging::default(),
+            log_json: false,
+            log_file: false,
+            error_handling: ErrorHandling::default(),
+            sysexits: false,
+            exit_codes: Vec::new(),
+            settings: false,
+            ci: Vec::new(),
+            global_options: default_global_options(),
+            args: Vec::new(),
+            groups: Vec::new(),
+            examples: Vec::new(),
+            commands: Vec::new(),
         }
     }
 }
@@ -0,0 +1,39 @@
+use crate::migrate::ConfigMigrator;
+use crate::models::config::CliConfig;
+use serde::{Deserialize, Serialize};
+
+/// File written to the root of every generated project.
+pub const MANIFEST_FILE: &str = ".cli-gen.toml";
+
+/// Directory holding the pristine rendering of each generated file, used as
+/// the common ancestor when `cli-gen update` merges template changes.
+pub const BASE_DIR: &str = ".cli-gen/base";
+
+/// Records how a project was generated so tooling can detect drift, update
+/// or re-create it.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ProjectManifest {
+    /// `BUILD_COMMIT_SHA` of the cli-gen build that rendered the project
+    #[serde(default)]
+    pub cli_gen_version: String,
+    /// RFC 3339 timestamp of the last rendering that changed the files
+    #[serde(default)]
+    pub generated_at: String,
+    /// Fingerprint of the template pack used for the last rendering
+    pub template_version: String,
+    #[serde(deserialize_with = "ConfigMigrator::deserialize")]
+    pub config: CliConfig,
+    /// Every generated file with the hash of its content as rendered
+    #[serde(default)]
+    pub files: Vec<ManifestFile>,
+}
+
+/// A generated file as recorded in the manifest.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestFile {
+    /// Path relative to the project root
+    pub path: String,
+    /// Hex-encoded SHA-256 of the rendered content
+    pub sha256: String,
+    pub mode: u32,
+}
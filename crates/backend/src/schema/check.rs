@@ -0,0 +1,199 @@
+use super::reader::{Node, NodeKind};
+use super::{ConfigSchema, Diagnostic, Severity};
+use serde_json::Value;
+
+impl ConfigSchema {
+    /// Check `node` at `path` against `schema`, a part of the schema `root`.
+    pub(super) fn check(
+        root: &Value,
+        schema: &Value,
+        node: &Node,
+        path: &str,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        if let Some(reference) = schema["$ref"].as_str() {
+            let name = reference.trim_start_matches("#/$defs/");
+            return Self::check(root, &root["$defs"][name], node, path, out);
+        }
+        if let Some(alternatives) = schema["oneOf"].as_array() {
+            return Self::check_one_of(root, alternatives, node, path, out);
+        }
+        if let Some(error) = Self::check_type(schema, node) {
+            out.push(Diagnostic::error(node, path, error));
+            return;
+        }
+
+        let errors = match &node.kind {
+            NodeKind::Number(text, _) => Self::check_range(schema, text),
+            NodeKind::String(value) => Self::check_length(schema, value),
+            NodeKind::Array(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    let child = format!("{}/{}", path, i);
+                    Self::check(root, &schema["items"], item, &child, out);
+                }
+                Vec::new()
+            }
+            NodeKind::Object(entries) => {
+                Self::check_object(root, schema, node, entries, path, out);
+                Vec::new()
+            }
+            NodeKind::Null | NodeKind::Bool(_) => Vec::new(),
+        };
+        out.extend(
+            errors
+                .into_iter()
+                .map(|error| Diagnostic::error(node, path, error)),
+        );
+    }
+
+    /// Why `node` does not have one of the `type`s or `enum` values of
+    /// `schema`, if it does not.
+    fn check_type(schema: &Value, node: &Node) -> Option<String> {
+        if let Some(types) = schema.get("type") {
+            let types: Vec<&str> = match types {
+                Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+                other => other.as_str().into_iter().collect(),
+            };
+            let found = node.type_name();
+            if !types
+                .iter()
+                .any(|ty| *ty == found || (*ty == "number" && found == "integer"))
+            {
+                return Some(format!("expected {}, found {}", types.join(" or "), found));
+            }
+        }
+        let values = schema["enum"].as_array()?;
+        if values.contains(&node.to_value()) {
+            return None;
+        }
+        let allowed: Vec<String> = values.iter().map(Value::to_string).collect();
+        Some(format!(
+            "{} is not one of {}",
+            node.to_value(),
+            allowed.join(", ")
+        ))
+    }
+
+    fn check_range(schema: &Value, text: &str) -> Vec<String> {
+        let number: f64 = text.parse().unwrap_or_default();
+        let mut errors = Vec::new();
+        if let Some(minimum) = schema["minimum"].as_f64()
+            && number < minimum
+        {
+            errors.push(format!("{} is less than {}", text, minimum));
+        }
+        if let Some(maximum) = schema["maximum"].as_f64()
+            && number > maximum
+        {
+            errors.push(format!("{} is greater than {}", text, maximum));
+        }
+        errors
+    }
+
+    fn check_length(schema: &Value, value: &str) -> Vec<String> {
+        let length = value.chars().count() as u64;
+        let characters = |count: u64| match count {
+            1 => "1 character".to_string(),
+            _ => format!("{} characters", count),
+        };
+        let mut errors = Vec::new();
+        if let Some(min) = schema["minLength"].as_u64()
+            && length < min
+        {
+            errors.push(format!("\"{}\" is shorter than {}", value, characters(min)));
+        }
+        if let Some(max) = schema["maxLength"].as_u64()
+            && length > max
+        {
+            errors.push(format!("\"{}\" is longer than {}", value, characters(max)));
+        }
+        errors
+    }
+
+    /// Check the required and known properties of an object. Unknown ones
+    /// are errors in closed objects and warnings elsewhere, as serde ignores
+    /// them.
+    fn check_object(
+        root: &Value,
+        schema: &Value,
+        node: &Node,
+        entries: &[(Node, Node)],
+        path: &str,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        let keys: Vec<&str> = entries.iter().filter_map(|(key, _)| key.as_str()).collect();
+        for required in schema["required"].as_array().into_iter().flatten() {
+            let required = required.as_str().unwrap_or_default();
+            if !keys.contains(&required) {
+                let message = format!("missing required property \"{}\"", required);
+                out.push(Diagnostic::error(node, path, message));
+            }
+        }
+        for (key, value) in entries {
+            let key_name = key.as_str().unwrap_or_default();
+            let child = format!("{}/{}", path, key_name);
+            if let Some(property) = schema["properties"].get(key_name) {
+                Self::check(root, property, value, &child, out);
+            } else if schema["additionalProperties"] == Value::Bool(false) {
+                let message = format!("unknown property \"{}\"", key_name);
+                out.push(Diagnostic::error(key, &child, message));
+            } else if schema.get("properties").is_some() {
+                let message = format!("unknown property \"{}\" is ignored", key_name);
+                out.push(Diagnostic::warning(key, &child, message));
+            }
+        }
+    }
+
+    /// Accept the first alternative without errors, else report the errors
+    /// of the one with the same shape as `node`, or list the accepted forms.
+    fn check_one_of(
+        root: &Value,
+        alternatives: &[Value],
+        node: &Node,
+        path: &str,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        let mut attempts = Vec::new();
+        for alternative in alternatives {
+            let mut diagnostics = Vec::new();
+            Self::check(root, alternative, node, path, &mut diagnostics);
+            if diagnostics.iter().all(|d| d.severity == Severity::Warning) {
+                out.extend(diagnostics);
+                return;
+            }
+            attempts.push((alternative, diagnostics));
+        }
+
+        let tag = match &node.kind {
+            NodeKind::Object(entries) => entries.first().and_then(|(key, _)| key.as_str()),
+            _ => None,
+        };
+        let same_shape = attempts.iter().position(|(alternative, _)| match tag {
+            Some(tag) => alternative["properties"].get(tag).is_some(),
+            None => alternative.get("enum").is_some() && node.as_str().is_some(),
+        });
+        match same_shape {
+            Some(i) if tag.is_some() => out.extend(attempts.swap_remove(i).1),
+            _ => {
+                let message = format!("expected one of {}", Self::forms(alternatives).join(", "));
+                out.push(Diagnostic::error(node, path, message));
+            }
+        }
+    }
+
+    /// The values and `{tag: ...}` objects the `alternatives` accept.
+    fn forms(alternatives: &[Value]) -> Vec<String> {
+        alternatives
+            .iter()
+            .flat_map(|alternative| match alternative["enum"].as_array() {
+                Some(values) => values.iter().map(Value::to_string).collect(),
+                None => alternative["required"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|tag| format!("{{{}: ...}}", tag))
+                    .collect::<Vec<_>>(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,247 @@
+use super::ConfigSchema;
+use crate::models::config::CONFIG_SCHEMA_VERSION;
+use serde_json::{Map, Value, json};
+
+impl ConfigSchema {
+    /// The JSON Schema (draft 2020-12) of `CliConfig`.
+    ///
+    /// Objects accept unknown properties like serde does; `validate` warns
+    /// about them. Only the wrappers of enum variants are closed, as serde
+    /// rejects extra keys there.
+    pub fn schema() -> Value {
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "CliConfig",
+            "description": "Configuration of a CLI project generated by cli-gen",
+            "type": "object",
+            "required": [
+                "name", "short_description", "long_description", "author", "copyright",
+                "license", "repository", "version", "sw_cli_url", "version_support",
+                "help_support"
+            ],
+            "properties": Self::merged([Self::package_properties(), Self::feature_properties()]),
+            "$defs": Self::merged([
+                Self::enum_definitions(),
+                Self::command_definitions(),
+                Self::arg_definitions()
+            ])
+        })
+    }
+
+    /// The entries of all the objects `parts` in one object.
+    fn merged<const N: usize>(parts: [Value; N]) -> Value {
+        let mut merged = Map::new();
+        for part in parts {
+            if let Value::Object(part) = part {
+                merged.extend(part);
+            }
+        }
+        Value::Object(merged)
+    }
+
+    /// The top-level properties describing the crate and its dependencies.
+    fn package_properties() -> Value {
+        json!({
+            "schema_version": {
+                "type": "integer",
+                "minimum": 0,
+                "maximum": CONFIG_SCHEMA_VERSION,
+                "description": "Version of the configuration format; older documents are upgraded on load"
+            },
+            "name": { "type": "string", "description": "Name of the CLI and its crate" },
+            "short_description": { "type": "string" },
+            "long_description": { "type": "string" },
+            "author": { "type": "string" },
+            "copyright": { "type": "string" },
+            "license": { "$ref": "#/$defs/LicenseType" },
+            "repository": { "type": "string" },
+            "version": { "type": "string" },
+            "sw_cli_url": { "type": "string" },
+            "sw_cli_dependency": { "$ref": "#/$defs/SwCliDependency" },
+            "version_support": { "type": "boolean" },
+            "help_support": { "type": "boolean" },
+            "architecture": { "$ref": "#/$defs/Architecture" },
+            "layout": { "$ref": "#/$defs/Layout" }
+        })
+    }
+
+    /// The top-level properties selecting what gets generated.
+    fn feature_properties() -> Value {
+        let feature = |description: &str| json!({ "type": "boolean", "description": description });
+        let list = |definition: &str| json!({ "type": "array", "items": { "$ref": format!("#/$defs/{}", definition) } });
+        json!({
+            "snapshot_tests": feature("Emit golden-file snapshot tests of every command's --help output"),
+            "example_tests": feature("Emit unit tests checking that every example command line parses"),
+            "completions": feature("Add a `completions <shell>` subcommand printing shell completions"),
+            "man_pages": feature("Add examples/mangen.rs rendering man pages with clap_mangen"),
+            "xtask": feature("Emit an xtask crate with the release tasks instead of shell scripts"),
+            "logging": { "$ref": "#/$defs/Logging" },
+            "log_json": feature("Emit logs as JSON lines (tracing backend)"),
+            "log_file": feature("Add a `--log-file <FILE>` option writing logs to a file"),
+            "error_handling": { "$ref": "#/$defs/ErrorHandling" },
+            "sysexits": feature("Include the sysexits(3) codes and exit with EX_USAGE (64) on usage errors"),
+            "exit_codes": list("ExitCodeConfig"),
+            "settings": feature("Add a layered TOML settings loader and a `config` subcommand"),
+            "ci": list("CiTarget"),
+            "global_options": {
+                "type": "array",
+                "items": { "$ref": "#/$defs/GlobalOption" },
+                "default": ["Verbose", "DryRun"]
+            },
+            "args": list("ArgConfig"),
+            "groups": list("ArgGroupConfig"),
+            "examples": list("ExampleConfig"),
+            "commands": list("CommandConfig")
+        })
+    }
+
+    /// The `$defs` of the enums of `CliConfig`.
+    fn enum_definitions() -> Value {
+        let string_enum = |description: &str, values: &[&str]| json!({ "description": description, "enum": values });
+        json!({
+            "LicenseType": {
+                "oneOf": [
+                    { "enum": ["MIT", "Apache2", "GPL3"] },
+                    Self::tagged("Custom", json!({ "type": "string" }))
+                ]
+            },
+            "SwCliDependency": {
+                "description": "How the generated project depends on sw-cli",
+                "oneOf": [
+                    { "enum": ["Submodule", "Standalone"] },
+                    Self::tagged("Git", json!({
+                        "type": "object",
+                        "properties": {
+                            "rev": { "type": ["string", "null"] },
+                            "tag": { "type": ["string", "null"] }
+                        }
+                    })),
+                    Self::tagged("Path", Self::string_struct("path")),
+                    Self::tagged("Registry", Self::string_struct("version")),
+                    Self::tagged("Vendored", Self::string_struct("source"))
+                ]
+            },
+            "Architecture": string_enum("Module structure of the generated code", &["Simple", "Dispatch"]),
+            "Layout": string_enum(
+                "How the generated sources are split into files and crates",
+                &["SingleFile", "Split", "CommandsDir", "Workspace"]
+            ),
+            "GlobalOption": string_enum(
+                "A standard option of the generated parser",
+                &["Verbose", "DryRun", "Quiet", "Verbosity", "Color", "ConfigFile", "Output", "NoInput"]
+            ),
+            "Logging": string_enum("Logging backend wired to the verbosity options", &["None", "Tracing", "EnvLogger"]),
+            "ErrorHandling": string_enum(
+                "Error type and exit-code mapping of the generated CLI",
+                &["None", "Thiserror", "Anyhow"]
+            ),
+            "CiTarget": string_enum(
+                "A CI system the generated project gets a configuration for",
+                &["GitHubActions", "GitLab", "Makefile"]
+            ),
+            "ArgKind": string_enum(
+                "Flag, Count, Option (the default) or Positional",
+                &["Flag", "Count", "Option", "Positional"]
+            )
+        })
+    }
+
+    /// The `$defs` of exit codes, commands and examples.
+    fn command_definitions() -> Value {
+        json!({
+            "ExitCodeConfig": {
+                "type": "object",
+                "required": ["name", "code"],
+                "properties": {
+                    "name": { "type": "string", "description": "Kebab-case name, e.g. not-found" },
+                    "code": { "type": "integer", "minimum": 0, "maximum": 255 },
+                    "description": { "type": "string" }
+                }
+            },
+            "CommandConfig": {
+                "type": "object",
+                "required": ["name"],
+                "properties": {
+                    "name": { "type": "string", "description": "Command name (kebab-case)" },
+                    "description": { "type": "string" },
+                    "args": { "type": "array", "items": { "$ref": "#/$defs/ArgConfig" } },
+                    "groups": { "type": "array", "items": { "$ref": "#/$defs/ArgGroupConfig" } },
+                    "examples": { "type": "array", "items": { "$ref": "#/$defs/ExampleConfig" } }
+                }
+            },
+            "ExampleConfig": {
+                "type": "object",
+                "required": ["command_line"],
+                "properties": {
+                    "command_line": { "type": "string" },
+                    "description": { "type": "string" }
+                }
+            }
+        })
+    }
+
+    /// The `$defs` of arguments, their values and groups.
+    fn arg_definitions() -> Value {
+        json!({
+            "ArgConfig": {
+                "type": "object",
+                "required": ["name"],
+                "properties": {
+                    "name": { "type": "string", "description": "Field name (snake_case)" },
+                    "description": { "type": "string" },
+                    "kind": { "$ref": "#/$defs/ArgKind" },
+                    "short": { "type": ["string", "null"], "minLength": 1, "maxLength": 1 },
+                    "long": { "type": ["string", "null"] },
+                    "value_type": { "type": "string", "default": "String" },
+                    "value_name": { "type": ["string", "null"] },
+                    "required": { "type": "boolean" },
+                    "multiple": { "type": "boolean" },
+                    "default_value": { "type": ["string", "null"] },
+                    "env": { "type": "boolean" },
+                    "conflicts_with": { "$ref": "#/$defs/Names" },
+                    "requires": { "$ref": "#/$defs/Names" },
+                    "required_unless_present": { "$ref": "#/$defs/Names" },
+                    "values": { "type": "array", "items": { "$ref": "#/$defs/ValueConfig" } }
+                }
+            },
+            "ValueConfig": {
+                "type": "object",
+                "required": ["name"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "description": { "type": "string" }
+                }
+            },
+            "ArgGroupConfig": {
+                "type": "object",
+                "required": ["name", "args"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "args": { "$ref": "#/$defs/Names" },
+                    "required": { "type": "boolean" },
+                    "multiple": { "type": "boolean" }
+                }
+            },
+            "Names": { "type": "array", "items": { "type": "string" } }
+        })
+    }
+
+    /// A `{ "<variant>": <content> }` enum variant as serde writes it.
+    fn tagged(variant: &str, content: Value) -> Value {
+        json!({
+            "type": "object",
+            "required": [variant],
+            "additionalProperties": false,
+            "properties": { variant: content }
+        })
+    }
+
+    /// An object with a single required string `field`.
+    fn string_struct(field: &str) -> Value {
+        json!({
+            "type": "object",
+            "required": [field],
+            "properties": { field: { "type": "string" } }
+        })
+    }
+}
@@ -0,0 +1,151 @@
+mod check;
+mod definitions;
+mod reader;
+
+use crate::codegen::generators::CodeGenerator;
+use crate::migrate::ConfigMigrator;
+use reader::{Node, NodeKind, Reader};
+use serde_json::Value;
+use std::fmt;
+
+/// JSON Schema of the `CliConfig` documents read by `batch`, `update` and the
+/// web UI, and a validator reporting every problem of a document.
+pub struct ConfigSchema;
+
+/// Whether a problem stops the document from loading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    /// The document loads, but probably not as meant, e.g. a misspelt
+    /// optional setting that is ignored
+    Warning,
+}
+
+/// A problem found in a configuration document, at a 1-based line and
+/// column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub line: usize,
+    pub column: usize,
+    /// JSON pointer of the offending value, e.g. `/commands/0/name`
+    pub path: String,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn error(node: &Node, path: &str, message: String) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            line: node.line,
+            column: node.column,
+            path: path.to_string(),
+            message,
+        }
+    }
+
+    fn warning(node: &Node, path: &str, message: String) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            ..Self::error(node, path, message)
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        if self.severity == Severity::Warning {
+            write!(f, "warning: ")?;
+        }
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl ConfigSchema {
+    /// Every syntax, schema and semantic problem of the document `source`.
+    /// Semantic errors point at the value they are about.
+    pub fn validate(source: &str) -> Vec<Diagnostic> {
+        let document = match serde_json::from_str::<Value>(source) {
+            Ok(document) => document,
+            Err(e) => {
+                let message = e.to_string();
+                let message = message
+                    .rsplit_once(" at line ")
+                    .map_or(message.as_str(), |(message, _)| message);
+                return vec![Diagnostic {
+                    severity: Severity::Error,
+                    line: e.line(),
+                    column: e.column(),
+                    path: String::new(),
+                    message: message.to_string(),
+                }];
+            }
+        };
+        let Some(root) = Reader::new(source).value() else {
+            return Vec::new();
+        };
+
+        let schema = Self::schema();
+        let mut diagnostics = Vec::new();
+        Self::check(&schema, &schema, &root, "", &mut diagnostics);
+
+        // serde skips what the schema flags as unknown, and falls back to the
+        // default of a setting with a schema error once it is left out, so
+        // such a document may still have semantic errors of its own
+        match ConfigMigrator::from_value(Self::without_errors(document, &diagnostics)) {
+            Ok(config) => {
+                diagnostics.extend(CodeGenerator::validation_errors(&config).into_iter().map(
+                    |error| {
+                        Diagnostic::error(
+                            Self::node_at(&root, &error.path),
+                            &error.path,
+                            error.message,
+                        )
+                    },
+                ));
+            }
+            // The schema errors already say why it does not load
+            Err(_) if diagnostics.iter().any(|d| d.severity == Severity::Error) => {}
+            Err(e) => diagnostics.push(Diagnostic::error(&root, "", e.to_string())),
+        }
+        diagnostics
+    }
+
+    /// `document` without the object properties the schema errors of
+    /// `diagnostics` point at.
+    fn without_errors(mut document: Value, diagnostics: &[Diagnostic]) -> Value {
+        for diagnostic in diagnostics.iter().filter(|d| d.severity == Severity::Error) {
+            if let Some((parent, key)) = diagnostic.path.rsplit_once('/')
+                && let Some(Value::Object(object)) = document.pointer_mut(parent)
+            {
+                object.remove(key);
+            }
+        }
+        document
+    }
+
+    /// The node at the JSON `pointer`, or the closest one on the way to it
+    /// if the document leaves the value out.
+    fn node_at<'a>(root: &'a Node, pointer: &str) -> &'a Node {
+        let mut node = root;
+        for segment in pointer.split('/').skip(1) {
+            let child = match &node.kind {
+                NodeKind::Object(entries) => entries
+                    .iter()
+                    .find(|(key, _)| key.as_str() == Some(segment))
+                    .map(|(_, value)| value),
+                NodeKind::Array(items) => segment.parse().ok().and_then(|i: usize| items.get(i)),
+                _ => None,
+            };
+            match child {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+        node
+    }
+}
@@ -0,0 +1,191 @@
+use serde_json::Value;
+
+/// A JSON value with the position it starts at.
+pub(super) struct Node {
+    pub(super) line: usize,
+    pub(super) column: usize,
+    pub(super) kind: NodeKind,
+}
+
+pub(super) enum NodeKind {
+    Null,
+    Bool(bool),
+    /// Source text of the number, and whether it is an integer
+    Number(String, bool),
+    String(String),
+    Array(Vec<Node>),
+    /// Entries with the node of each key
+    Object(Vec<(Node, Node)>),
+}
+
+impl Node {
+    pub(super) fn type_name(&self) -> &'static str {
+        match &self.kind {
+            NodeKind::Null => "null",
+            NodeKind::Bool(_) => "boolean",
+            NodeKind::Number(_, true) => "integer",
+            NodeKind::Number(..) => "number",
+            NodeKind::String(_) => "string",
+            NodeKind::Array(_) => "array",
+            NodeKind::Object(_) => "object",
+        }
+    }
+
+    pub(super) fn as_str(&self) -> Option<&str> {
+        match &self.kind {
+            NodeKind::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value as JSON, for comparing with `enum` entries.
+    pub(super) fn to_value(&self) -> Value {
+        match &self.kind {
+            NodeKind::Null => Value::Null,
+            NodeKind::Bool(value) => Value::Bool(*value),
+            NodeKind::Number(text, _) => serde_json::from_str(text).unwrap_or(Value::Null),
+            NodeKind::String(value) => Value::String(value.clone()),
+            NodeKind::Array(items) => Value::Array(items.iter().map(Node::to_value).collect()),
+            NodeKind::Object(entries) => Value::Object(
+                entries
+                    .iter()
+                    .map(|(key, value)| {
+                        (
+                            key.as_str().unwrap_or_default().to_string(),
+                            value.to_value(),
+                        )
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Parser of documents already known to be valid JSON, recording where each
+/// value starts.
+pub(super) struct Reader<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(source: &'a str) -> Self {
+        Reader {
+            chars: source.chars().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+            self.next();
+        }
+    }
+
+    pub(super) fn value(&mut self) -> Option<Node> {
+        self.skip_whitespace();
+        let (line, column) = (self.line, self.column);
+        let kind = match *self.chars.peek()? {
+            '{' => NodeKind::Object(self.object()?),
+            '[' => NodeKind::Array(self.array()?),
+            '"' => NodeKind::String(self.string()?),
+            't' | 'f' | 'n' => self.literal(),
+            _ => self.number(),
+        };
+        Some(Node { line, column, kind })
+    }
+
+    fn object(&mut self) -> Option<Vec<(Node, Node)>> {
+        self.next();
+        let mut entries = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek()? {
+                '}' => break,
+                ',' => {
+                    self.next();
+                }
+                _ => {
+                    let key = self.value()?;
+                    self.skip_whitespace();
+                    self.next(); // ':'
+                    entries.push((key, self.value()?));
+                }
+            }
+        }
+        self.next();
+        Some(entries)
+    }
+
+    fn array(&mut self) -> Option<Vec<Node>> {
+        self.next();
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek()? {
+                ']' => break,
+                ',' => {
+                    self.next();
+                }
+                _ => items.push(self.value()?),
+            }
+        }
+        self.next();
+        Some(items)
+    }
+
+    /// `true`, `false` or `null`.
+    fn literal(&mut self) -> NodeKind {
+        let mut word = String::new();
+        while self.chars.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            word.extend(self.next());
+        }
+        match word.as_str() {
+            "true" => NodeKind::Bool(true),
+            "false" => NodeKind::Bool(false),
+            _ => NodeKind::Null,
+        }
+    }
+
+    fn number(&mut self) -> NodeKind {
+        let mut text = String::new();
+        while self
+            .chars
+            .peek()
+            .is_some_and(|c| c.is_ascii_digit() || "+-.eE".contains(*c))
+        {
+            text.extend(self.next());
+        }
+        let integer = !text.contains(['.', 'e', 'E']);
+        NodeKind::Number(text, integer)
+    }
+
+    /// A string literal, with its escapes decoded by serde_json.
+    fn string(&mut self) -> Option<String> {
+        let mut raw = String::from('"');
+        self.next();
+        loop {
+            let c = self.next()?;
+            raw.push(c);
+            match c {
+                '\\' => raw.extend(self.next()),
+                '"' => break,
+                _ => {}
+            }
+        }
+        serde_json::from_str(&raw).ok()
+    }
+}
@@ -5,10 +5,7 @@ echo "Building {{ name }}..."
 echo "===================="
 echo
 
-# Always ensure submodules are initialized and updated to latest
-echo "Updating git submodules to latest..."
-git submodule update --init --recursive --remote --merge
-
+{{ sw_cli_update }}
 echo
 echo "Building release binary..."
 cargo build --release
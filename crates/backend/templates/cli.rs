@@ -1,22 +1,22 @@
 use clap::Parser;
+{{ global_imports }}
 
 #[derive(Parser, Debug)]
 #[command(name = "{{ name }}")]
 #[command(author = "{{ author }}")]
-#[command(about = sw_cli::short_help!())]
-#[command(long_about = sw_cli::long_help!())]
+#[command(about = {{ short_help }})]
+#[command(long_about = {{ long_help }})]
+{{ arg_groups }}
 pub struct Cli {
-    /// Enable verbose output
-    #[arg(short, long)]
-    pub verbose: bool,
-
-    /// Dry-run mode (show what would be done without doing it)
-    #[arg(short = 'n', long)]
-    pub dry_run: bool,
-
+{{ global_args }}
+{{ args }}
     // TODO: Add your CLI-specific arguments here
     // Example:
     // /// Path to input file
     // #[arg(short, long, value_name = "FILE")]
     // pub input: Option<PathBuf>,
 }
+{{ global_support }}
+{{ commands }}
+{{ value_enums }}
+{{ example_tests }}
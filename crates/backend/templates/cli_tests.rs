@@ -0,0 +1,9 @@
+//! Integration tests exercising the command-line interface of {{ name }}.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_{{ name }}"))
+}
+{{ cli_tests }}
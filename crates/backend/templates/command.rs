@@ -0,0 +1,11 @@
+{{ command_imports }}
+{{ command_struct }}
+
+impl {{ command_type }} {
+    pub fn run(&self, {{ cli_param }}: &Cli) -> {{ result_type }} {
+{{ verbose_trace }}
+        // TODO: Implement the {{ command_name }} command
+        println!("{{ command_name }} is not implemented yet");
+        Ok(())
+    }
+}
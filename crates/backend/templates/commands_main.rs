@@ -0,0 +1,14 @@
+{{ main_imports }}
+{{ main_modules }}
+
+fn main(){{ main_return }} {
+    // Check for version flag with detailed output before argument parsing
+    if {{ version_crate }}::check_version_flag() {
+        println!("{}", {{ version_crate }}::version!());
+        return{{ main_success }};
+    }
+
+    let cli = {{ parse_cli }};
+{{ main_logging }}
+{{ main_run }}
+}
@@ -0,0 +1,17 @@
+{{ command_imports }}
+
+{{ command_modules }}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum Commands {
+{{ command_variants }}
+}
+
+impl Commands {
+    /// Run the selected command.
+    pub fn run(&self, cli: &Cli) -> {{ result_type }} {
+        match self {
+{{ command_runs }}
+        }
+    }
+}
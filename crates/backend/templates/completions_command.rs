@@ -0,0 +1,12 @@
+{{ command_imports }}
+{{ command_struct }}
+
+impl CompletionsCommand {
+    /// Print completions for the selected shell to stdout.
+    pub fn run(&self, _cli: &Cli) -> {{ result_type }} {
+        let mut command = <Cli as clap::CommandFactory>::command();
+        let name = command.get_name().to_string();
+        clap_complete::generate(self.shell, &mut command, name, &mut std::io::stdout());
+        Ok(())
+    }
+}
@@ -1,96 +1,45 @@
-use clap::{Parser, Subcommand};
-use std::error::Error;
+use clap::Parser;
+{{ global_imports }}
 
-/// {{ long_description }}
-#[derive(Parser)]
-#[clap(name = "{{ name }}", version = env!("CLI_VERSION"), about = "{{ short_description }}")]
+/// {{ short_description }}
+#[derive(Parser, Debug)]
+#[command(name = "{{ name }}")]
+#[command(author = "{{ author }}")]
+#[command(about = {{ short_help }})]
+#[command(long_about = {{ long_help }})]
+{{ arg_groups }}
 pub struct CliConfig {
-    #[clap(subcommand)]
-    pub command: Option<Commands>,
-    
-    /// Show version information
-    #[clap(short = 'V', long = "version", global = true)]
-    pub show_version: bool,
-    
-    /// Verbose mode
-    #[clap(short = 'v', long = "verbose", global = true)]
-    pub verbose: bool,
+{{ global_args }}
+{{ args }}
 }
+{{ global_support }}
+{{ commands }}
+{{ value_enums }}
 
-#[derive(Subcommand)]
-pub enum Commands {
-    /// Example command
-    #[clap(name = "example", about = "An example command")]
-    Example {
-        /// Example argument
-        #[clap(short = 'n', long = "name", default_value = "world")]
-        name: String,
-    },
+// Builder pattern implementation
+pub struct CliConfigBuilder {
+{{ builder_fields }}
 }
 
-impl CliConfig {
-    pub fn new() -> Result<Self, Box<dyn Error>> {
-        let config = Self::parse();
-        Ok(config)
-    }
-    
-    pub fn execute(&self) -> Result<(), Box<dyn Error>> {
-        if self.show_version {
-            println!("{{ name }} v{}", env!("CLI_VERSION"));
-            return Ok(());
-        }
-        
-        match &self.command {
-            Some(Commands::Example { name }) => {
-                println!("Hello, {}!", name);
-            }
-            None => {
-                // Default behavior when no subcommand is provided
-                println!("{{ short_description }}");
-                println!("Use --help for more information.");
-            }
-        }
-        
-        Ok(())
+impl Default for CliConfigBuilder {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-// Builder pattern implementation
-pub struct CliConfigBuilder {
-    show_version: bool,
-    verbose: bool,
-    command: Option<Commands>,
-}
-
 impl CliConfigBuilder {
     pub fn new() -> Self {
         Self {
-            show_version: false,
-            verbose: false,
-            command: None,
+{{ builder_defaults }}
         }
     }
-    
-    pub fn show_version(mut self, show: bool) -> Self {
-        self.show_version = show;
-        self
-    }
-    
-    pub fn verbose(mut self, verbose: bool) -> Self {
-        self.verbose = verbose;
-        self
-    }
-    
-    pub fn command(mut self, command: Commands) -> Self {
-        self.command = Some(command);
-        self
-    }
-    
-    pub fn build(self) -> CliConfig {
-        CliConfig {
-            show_version: self.show_version,
-            verbose: self.verbose,
-            command: self.command,
-        }
+{{ builder_setters }}
+
+    /// Build the configuration, failing if a required value was not set.
+    pub fn build(self) -> Result<CliConfig, String> {
+        Ok(CliConfig {
+{{ builder_build }}
+        })
     }
-}
\ No newline at end of file
+}
+{{ example_tests }}
@@ -1,41 +1,23 @@
-use clap::Parser;
-use std::error::Error;
-
-use crate::config::{CliConfig, Commands};
+{{ dispatch_imports }}
 
 pub struct CliDispatcher;
 
 impl CliDispatcher {
-    pub fn dispatch(config: &CliConfig) -> Result<(), Box<dyn Error>> {
+    pub fn dispatch(config: &CliConfig) -> {{ result_type }} {
         match &config.command {
-            Some(Commands::Example { name }) => {
-                Self::handle_example(name)
-            }
+{{ dispatch_arms }}
             None => {
-                // Default behavior - already handled in config
+                // Default behavior when no subcommand is provided
+                println!("{{ short_description }}");
+                println!("Use --help for more information.");
                 Ok(())
             }
         }
     }
-
-    fn handle_example(name: &str) -> Result<(), Box<dyn Error>> {
-        // Example command handler
-        println!("Executing example command with name: {}", name);
-        
-        // Add your example command logic here
-        Ok(())
-    }
-    
-    // Add more handler methods for additional commands
-    // Each method should match a command variant from config.rs
 }
 
-// Alternative dispatch implementation using a trait
+/// Implemented by the handler of each command.
 pub trait CommandHandler {
-    fn handle(&self) -> Result<(), Box<dyn Error>>;
+    fn handle(&self, config: &CliConfig) -> {{ result_type }};
 }
-
-// Example implementation for the Example command
-pub struct ExampleHandler {
-    pub name: String,
-}
\ No newline at end of file
+{{ handlers }}
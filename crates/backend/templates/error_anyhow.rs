@@ -0,0 +1,58 @@
+//! Errors of {{ name }} and the exit codes they end the process with.
+{{ error_lints }}
+
+use std::fmt;
+use std::process::ExitCode;
+
+pub use anyhow::{Error, Result};
+
+/// Exit codes of {{ name }}, as listed under EXIT CODES in `--help`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+{{ exit_variants }}
+}
+
+impl From<Exit> for ExitCode {
+    fn from(exit: Exit) -> Self {
+        ExitCode::from(exit as u8)
+    }
+}
+
+/// An error ending the process with a specific exit code, e.g.
+/// `return Err(ExitError::new(Exit::Failure, "nothing to do").into())`.
+#[derive(Debug)]
+pub struct ExitError {
+    pub exit: Exit,
+    pub message: String,
+}
+
+impl ExitError {
+    pub fn new(exit: Exit, message: impl Into<String>) -> Self {
+        ExitError {
+            exit,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ExitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ExitError {}
+
+/// Exit code for an error returned to `main`: that of the first
+/// `ExitError` in its chain of causes, else `Exit::Failure`.
+pub fn exit_code(error: &Error) -> ExitCode {
+    for cause in error.chain() {
+        if let Some(error) = cause.downcast_ref::<ExitError>() {
+            return error.exit.into();
+        }
+{{ io_exit_check }}
+{{ settings_exit_check }}
+    }
+    Exit::Failure.into()
+}
+{{ parse_args }}
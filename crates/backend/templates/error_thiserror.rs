@@ -0,0 +1,47 @@
+//! Errors of {{ name }} and the exit codes they end the process with.
+{{ error_lints }}
+
+use std::process::ExitCode;
+
+/// Exit codes of {{ name }}, as listed under EXIT CODES in `--help`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+{{ exit_variants }}
+}
+
+impl From<Exit> for ExitCode {
+    fn from(exit: Exit) -> Self {
+        ExitCode::from(exit as u8)
+    }
+}
+
+/// Errors returned by the commands of {{ name }}.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Reading or writing a file failed
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// Any other failure, described by its message
+    #[error("{0}")]
+    Other(String),
+{{ error_variants }}
+}
+
+impl Error {
+    /// Exit code of the process when this error reaches `main`.
+    pub fn exit(&self) -> Exit {
+        match self {
+            Error::Io(_) => Exit::{{ io_exit }},
+            Error::Other(_) => Exit::Failure,
+{{ error_exits }}
+        }
+    }
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Exit code for an error returned to `main`.
+pub fn exit_code(error: &Error) -> ExitCode {
+    error.exit().into()
+}
+{{ parse_args }}
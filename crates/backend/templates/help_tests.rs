@@ -0,0 +1,47 @@
+//! Snapshot tests of the `--help` output of {{ name }}.
+//!
+//! Snapshots live in `tests/snapshots/` and should be committed. A missing
+//! snapshot is recorded on the first run outside CI. After an intended change
+//! to the help text, refresh them with `UPDATE_SNAPSHOTS=1 cargo test --test
+//! help` or `cli-gen refresh-snapshots`.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Compare the `--help` output of the command at `args` with its snapshot.
+fn assert_help(args: &[&str]) {
+    let output = Command::new(env!("CARGO_BIN_EXE_{{ name }}"))
+        .args(args)
+        .arg("--help")
+        .output()
+        .expect("failed to run {{ name }}");
+    assert!(output.status.success(), "--help failed: {:?}", output);
+    let actual = String::from_utf8(output.stdout).expect("help output is not UTF-8");
+
+    let file_name = match args {
+        [] => "help.txt".to_string(),
+        _ => format!("{}-help.txt", args.join("-")),
+    };
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(file_name);
+
+    let record = std::env::var_os("UPDATE_SNAPSHOTS").is_some()
+        || (!path.exists() && std::env::var_os("CI").is_none());
+    if record {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("cannot read snapshot {}: {}", path.display(), e));
+    assert_eq!(
+        actual,
+        expected,
+        "--help output differs from {}; rerun with UPDATE_SNAPSHOTS=1 if the change is intended",
+        path.display()
+    );
+}
+{{ help_snapshot_tests }}
@@ -1 +1 @@
-pub mod cli;
+{{ modules }}
@@ -0,0 +1,28 @@
+//! Logging setup from the verbosity options and `RUST_LOG`.
+
+use std::error::Error;
+{{ log_imports }}
+
+use log::LevelFilter;
+
+/// Install the global logger. `RUST_LOG`, when set, overrides the level
+/// derived from `quiet` and the number of `-v` flags.
+pub fn init(quiet: bool, verbosity: u8{{ log_file_param }}) -> Result<(), Box<dyn Error>> {
+    let mut builder = env_logger::Builder::new();
+    builder
+        .filter_level(default_level(quiet, verbosity))
+        .parse_default_env();
+{{ log_init }}
+    builder.try_init()?;
+    Ok(())
+}
+
+fn default_level(quiet: bool, verbosity: u8) -> LevelFilter {
+    match (quiet, verbosity) {
+        (true, _) => LevelFilter::Error,
+        (false, 0) => LevelFilter::Warn,
+        (false, 1) => LevelFilter::Info,
+        (false, 2) => LevelFilter::Debug,
+        (false, _) => LevelFilter::Trace,
+    }
+}
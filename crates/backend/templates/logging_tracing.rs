@@ -0,0 +1,27 @@
+//! Logging setup from the verbosity options and `RUST_LOG`.
+
+use std::error::Error;
+{{ log_imports }}
+
+use tracing_subscriber::EnvFilter;
+
+/// Install the global subscriber. `RUST_LOG`, when set, overrides the level
+/// derived from `quiet` and the number of `-v` flags.
+pub fn init(quiet: bool, verbosity: u8{{ log_file_param }}) -> Result<(), Box<dyn Error>> {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(default_level(quiet, verbosity)));
+    let subscriber = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr){{ log_json }};
+{{ log_init }}
+}
+
+fn default_level(quiet: bool, verbosity: u8) -> &'static str {
+    match (quiet, verbosity) {
+        (true, _) => "error",
+        (false, 0) => "warn",
+        (false, 1) => "info",
+        (false, 2) => "debug",
+        (false, _) => "trace",
+    }
+}
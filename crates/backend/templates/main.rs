@@ -1,16 +1,16 @@
-use clap::Parser;
-use {{ crate_name }}::cli::Cli;
+{{ main_imports }}
+{{ main_modules }}
 
-fn main() {
-    // Check for version flag with detailed output (handled by sw-cli)
-    if sw_cli::check_version_flag() {
-        println!("{}", sw_cli::version!());
-        return;
+fn main(){{ main_return }} {
+    // Check for version flag with detailed output before argument parsing
+    if {{ version_crate }}::check_version_flag() {
+        println!("{}", {{ version_crate }}::version!());
+        return{{ main_success }};
     }
 
-    let cli = Cli::parse();
-
-    // TODO: Implement your CLI logic here
-    println!("{{ name }} is running!");
-    println!("Config: {:?}", cli);
+    let cli = {{ parse_cli }};
+{{ main_logging }}
+{{ main_settings }}
+{{ main_completions }}
+{{ main_run }}
 }
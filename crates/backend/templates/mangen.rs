@@ -0,0 +1,65 @@
+//! Writes the man pages of {{ name }}, rendered from its clap definitions with
+//! clap_mangen, into the directory given as the first argument
+//! (default: `target/man`):
+//!
+//!     cargo run --example mangen -- target/man
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use clap::{Command, CommandFactory};
+use clap_mangen::Man;
+use clap_mangen::roff::{Roff, roman};
+use {{ lib_crate }}::{{ parser_module }}::{{ parser_type }};
+
+const ABOUT: &str = "{{ man_about }}";
+const LONG_ABOUT: &str = "{{ man_long_about }}";
+
+fn main() -> io::Result<()> {
+    let dir = PathBuf::from(
+        std::env::args()
+            .nth(1)
+            .unwrap_or_else(|| "target/man".to_string()),
+    );
+    fs::create_dir_all(&dir)?;
+
+    let mut command = {{ parser_type }}::command()
+        .about(ABOUT)
+        .long_about(LONG_ABOUT)
+        .author(env!("CARGO_PKG_AUTHORS"))
+        .version(env!("CARGO_PKG_VERSION"))
+        .disable_help_subcommand(true);
+    // Building names the subcommand pages `{{ name }}-<command>`
+    command.build();
+
+    write_page(&dir, &command)?;
+    for subcommand in command.get_subcommands() {
+        write_page(&dir, subcommand)?;
+    }
+    Ok(())
+}
+
+/// Render the page of `command` into `dir`, followed by LICENSE and
+/// REPOSITORY sections.
+fn write_page(dir: &Path, command: &Command) -> io::Result<()> {
+    let man = Man::new({{ man_page_command }});
+    let mut page = Vec::new();
+    man.render(&mut page)?;
+{{ man_environment }}
+{{ man_examples }}
+    let sections = Roff::new()
+        .control("SH", ["LICENSE"])
+        .text([roman(env!("CARGO_PKG_LICENSE"))])
+        .control("SH", ["REPOSITORY"])
+        .text([roman(env!("CARGO_PKG_REPOSITORY"))])
+        .to_roff();
+    page.extend_from_slice(sections.as_bytes());
+
+    let path = dir.join(man.get_filename());
+    fs::write(&path, page)?;
+    println!("Wrote {}", path.display());
+    Ok(())
+}
+{{ man_environment_fn }}
+{{ man_examples_fn }}
@@ -0,0 +1,179 @@
+//! Layered settings of {{ name }}. Each layer overrides the previous ones:
+//!
+//! 1. `$XDG_CONFIG_HOME/{{ name }}/config.toml` (default `~/.config/{{ name }}/config.toml`)
+//! 2. `.{{ name }}.toml` in the current directory
+//! 3. the file passed to `Settings::load`, e.g. with `--config`
+//! 4. `{{ env_prefix }}*` environment variables, `__` separating nested keys,
+//!    except those of arguments (`ARG_ENV_VARS`)
+//! 5. `--set KEY=VALUE` on the command line
+{{ settings_lints }}
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Prefix of the environment variables overriding settings.
+pub const ENV_PREFIX: &str = "{{ env_prefix }}";
+
+/// Variables command-line arguments fall back to, which are not settings
+/// despite their prefix.
+{{ arg_env_vars }}
+
+/// Where the value of a setting comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Env(String),
+    Flag,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Env(var) => write!(f, "${}", var),
+            Source::Flag => f.write_str("--set"),
+        }
+    }
+}
+
+/// A settings file or override that cannot be read.
+#[derive(Debug)]
+pub struct SettingsError(String);
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+/// The merged settings by dotted key, e.g. `server.port`.
+#[derive(Debug, Default)]
+pub struct Settings {
+    values: BTreeMap<String, (toml::Value, Source)>,
+    /// Files looked for, and whether they exist
+    files: Vec<(PathBuf, bool)>,
+}
+
+impl Settings {
+    /// Merge all layers. `config_file` must exist; the other files are
+    /// optional.
+    pub fn load(config_file: Option<&Path>, overrides: &[String]) -> Result<Self, SettingsError> {
+        let mut settings = Settings::default();
+        if let Some(path) = user_config_path() {
+            settings.merge_file(&path, false)?;
+        }
+        settings.merge_file(Path::new(".{{ name }}.toml"), false)?;
+        if let Some(path) = config_file {
+            settings.merge_file(path, true)?;
+        }
+
+        let mut vars: Vec<(String, String)> = env::vars()
+            .filter(|(var, _)| var.starts_with(ENV_PREFIX) && !ARG_ENV_VARS.contains(&var.as_str()))
+            .collect();
+        vars.sort();
+        for (var, value) in vars {
+            let key = var[ENV_PREFIX.len()..].to_lowercase().replace("__", ".");
+            settings.insert(key, parse_value(&value), Source::Env(var));
+        }
+
+        for entry in overrides {
+            let (key, value) = entry.split_once('=').ok_or_else(|| {
+                SettingsError(format!("invalid --set '{}', expected KEY=VALUE", entry))
+            })?;
+            let key = key.trim().to_string();
+            settings.insert(key, parse_value(value.trim()), Source::Flag);
+        }
+        Ok(settings)
+    }
+
+    /// Value of `key`, if any layer sets it.
+    pub fn get(&self, key: &str) -> Option<&toml::Value> {
+        self.values.get(key).map(|(value, _)| value)
+    }
+
+    /// The layer `key` was last set by.
+    pub fn source(&self, key: &str) -> Option<&Source> {
+        self.values.get(key).map(|(_, source)| source)
+    }
+
+    fn merge_file(&mut self, path: &Path, required: bool) -> Result<(), SettingsError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if !required && e.kind() == io::ErrorKind::NotFound => {
+                self.files.push((path.to_path_buf(), false));
+                return Ok(());
+            }
+            Err(e) => {
+                return Err(SettingsError(format!(
+                    "cannot read {}: {}",
+                    path.display(),
+                    e
+                )));
+            }
+        };
+        let table: toml::Table = text
+            .parse()
+            .map_err(|e| SettingsError(format!("invalid TOML in {}: {}", path.display(), e)))?;
+        self.files.push((path.to_path_buf(), true));
+        self.merge_table("", table, path);
+        Ok(())
+    }
+
+    fn merge_table(&mut self, prefix: &str, table: toml::Table, path: &Path) {
+        for (key, value) in table {
+            let key = if prefix.is_empty() {
+                key
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match value {
+                toml::Value::Table(table) => self.merge_table(&key, table, path),
+                value => self.insert(key, value, Source::File(path.to_path_buf())),
+            }
+        }
+    }
+
+    fn insert(&mut self, key: String, value: toml::Value, source: Source) {
+        self.values.insert(key, (value, source));
+    }
+}
+
+/// The files looked for, then every setting with its value and source.
+impl fmt::Display for Settings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (path, found) in &self.files {
+            let status = if *found { "read" } else { "not found" };
+            writeln!(f, "# {} ({})", path.display(), status)?;
+        }
+        for (key, (value, source)) in &self.values {
+            writeln!(f, "{} = {}  # {}", key, value, source)?;
+        }
+        Ok(())
+    }
+}
+
+/// `$XDG_CONFIG_HOME/{{ name }}/config.toml`, else under `~/.config`
+/// (`%APPDATA%` on Windows).
+fn user_config_path() -> Option<PathBuf> {
+    let dir = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))?;
+    Some(dir.join("{{ name }}").join("config.toml"))
+}
+
+/// A TOML value such as `8080`, `true` or `[1, 2]`, else the raw string.
+fn parse_value(raw: &str) -> toml::Value {
+    format!("value = {}", raw)
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(raw.to_string()))
+}
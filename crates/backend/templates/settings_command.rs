@@ -0,0 +1,11 @@
+{{ command_imports }}
+use crate::settings::Settings;
+{{ command_struct }}
+
+impl ConfigCommand {
+    /// Print the effective settings and where each value comes from.
+    pub fn run(&self, cli: &Cli) -> {{ result_type }} {
+        print!("{}", Settings::load({{ settings_args }})?);
+        Ok(())
+    }
+}
@@ -11,17 +11,7 @@ if [ ! -d ".git" ]; then
     git init
 fi
 
-# Add sw-cli submodule
-echo "Adding sw-cli submodule..."
-if [ -d "lib/sw-cli" ]; then
-    echo "sw-cli submodule already exists, skipping..."
-else
-    git submodule add {{ sw_cli_url }} lib/sw-cli
-fi
-
-# Initialize and update submodules
-echo "Updating submodules..."
-git submodule update --init --recursive
+{{ sw_cli_setup }}
 
 echo
 echo "✓ Setup complete!"
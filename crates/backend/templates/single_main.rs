@@ -0,0 +1,36 @@
+{{ main_imports }}
+{{ main_modules }}
+
+#[derive(Parser, Debug)]
+#[command(name = "{{ name }}")]
+#[command(author = "{{ author }}")]
+#[command(about = {{ short_help }})]
+#[command(long_about = {{ long_help }})]
+{{ arg_groups }}
+pub struct Cli {
+{{ global_args }}
+{{ args }}
+    // TODO: Add your CLI-specific arguments here
+    // Example:
+    // /// Path to input file
+    // #[arg(short, long, value_name = "FILE")]
+    // pub input: Option<PathBuf>,
+}
+{{ global_support }}
+{{ commands }}
+{{ value_enums }}
+
+fn main(){{ main_return }} {
+    // Check for version flag with detailed output before argument parsing
+    if {{ version_crate }}::check_version_flag() {
+        println!("{}", {{ version_crate }}::version!());
+        return{{ main_success }};
+    }
+
+    let cli = {{ parse_cli }};
+{{ main_logging }}
+{{ main_settings }}
+{{ main_completions }}
+{{ main_run }}
+}
+{{ example_tests }}
@@ -0,0 +1,43 @@
+//! Version information and early `-V/--version` handling, equivalent to the
+//! sw-cli helpers. Build metadata is provided by `build.rs`.
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const COPYRIGHT: &str = include_str!("../{{ project_root }}COPYRIGHT");
+pub const LICENSE_NAME: &str = env!("CARGO_PKG_LICENSE");
+pub const REPOSITORY: &str = env!("CARGO_PKG_REPOSITORY");
+pub const GIT_COMMIT_SHA: &str = env!("BUILD_COMMIT_SHA");
+pub const BUILD_HOST: &str = env!("BUILD_HOST");
+pub const BUILD_TIMESTAMP: &str = env!("BUILD_TIMESTAMP");
+
+/// Whether the first argument asks for version information.
+///
+/// Checked before argument parsing so `-V/--version` works even when other
+/// required arguments are missing.
+pub fn check_version_flag() -> bool {
+    matches!(
+        std::env::args().nth(1).as_deref(),
+        Some("-V") | Some("--version")
+    )
+}
+
+/// Version text including copyright, license and build metadata.
+pub fn version_text() -> String {
+    format!(
+        "Version: {}\n{}\n{} License: {}/blob/main/LICENSE\nBuild: {} @ {} ({})",
+        VERSION,
+        COPYRIGHT.trim(),
+        LICENSE_NAME,
+        REPOSITORY,
+        GIT_COMMIT_SHA,
+        BUILD_HOST,
+        BUILD_TIMESTAMP
+    )
+}
+
+/// Version text including build metadata, as printed by `-V/--version`.
+#[macro_export]
+macro_rules! version {
+    () => {
+        $crate::version::version_text()
+    };
+}
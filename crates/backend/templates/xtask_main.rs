@@ -0,0 +1,204 @@
+//! Development and release tasks of {{ name }}, run with `cargo xtask <task>`.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Name of the binary package.
+const BIN: &str = "{{ name }}";
+
+const USAGE: &str = "Usage: cargo xtask <task>
+
+Tasks:
+    setup                Initialize git and make sw-cli available
+    build                Build the release binary
+    dist                 Collect the release binary{{ dist_extras }} in target/dist
+    bump-version <part>  Bump the version: major, minor, patch or X.Y.Z";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        ["setup"] => setup(),
+        ["build"] => build(),
+        ["dist"] => dist(),
+        ["bump-version", part] => bump_version(part),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The project root, one level above this crate.
+fn project_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives inside the project")
+        .to_path_buf()
+}
+
+fn target_dir() -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| project_root().join("target"))
+}
+
+/// Run `program` with `args` in the project root, failing on a non-zero exit.
+fn run(program: &str, args: &[&str]) -> Result<()> {
+    let status = Command::new(program)
+        .args(args)
+        .current_dir(project_root())
+        .status()?;
+    if !status.success() {
+        return Err(format!("`{} {}` failed with {}", program, args.join(" "), status).into());
+    }
+    Ok(())
+}
+
+fn setup() -> Result<()> {
+    if !project_root().join(".git").exists() {
+        println!("Initializing git repository...");
+        run("git", &["init"])?;
+    }
+{{ xtask_setup }}
+
+    println!("Setup complete");
+    Ok(())
+}
+
+fn build() -> Result<()> {
+{{ xtask_update }}
+    run("cargo", &["build", "--release", "--package", BIN])?;
+    println!("Binary location: {}", release_binary().display());
+    Ok(())
+}
+
+fn release_binary() -> PathBuf {
+    target_dir()
+        .join("release")
+        .join(format!("{}{}", BIN, env::consts::EXE_SUFFIX))
+}
+
+/// Build the release binary and collect everything shipped with it in
+/// `target/dist`.
+fn dist() -> Result<()> {
+    build()?;
+
+    let dist = target_dir().join("dist");
+    if dist.exists() {
+        fs::remove_dir_all(&dist)?;
+    }
+    fs::create_dir_all(&dist)?;
+
+    let binary = release_binary();
+    fs::copy(&binary, dist.join(binary.file_name().unwrap()))?;
+{{ xtask_dist }}
+
+    println!("Distribution written to {}", dist.display());
+    Ok(())
+}
+
+fn bump_version(part: &str) -> Result<()> {
+    let path = project_root().join("Cargo.toml");
+    let manifest = fs::read_to_string(&path)?;
+    let (updated, old, new) = bump_manifest(&manifest, part)?;
+    fs::write(&path, updated)?;
+    println!("Bumped version from {} to {}", old, new);
+    Ok(())
+}
+
+/// Bump the version of `[package]` or `[workspace.package]` in `manifest`,
+/// returning the new manifest with the old and new versions.
+fn bump_manifest(manifest: &str, part: &str) -> Result<(String, String, String)> {
+    let mut section = "";
+    let mut bumped = None;
+    let mut lines = Vec::new();
+    for line in manifest.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            section = trimmed;
+        }
+        let current = trimmed
+            .strip_prefix("version = \"")
+            .and_then(|rest| rest.strip_suffix('"'));
+        match current {
+            Some(current)
+                if bumped.is_none() && matches!(section, "[package]" | "[workspace.package]") =>
+            {
+                let new = next_version(current, part)?;
+                lines.push(format!("version = \"{}\"", new));
+                bumped = Some((current.to_string(), new));
+            }
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    let (old, new) = bumped.ok_or("no package version found in Cargo.toml")?;
+    Ok((lines.join("\n") + "\n", old, new))
+}
+
+/// The version after `current` for `part`: major, minor, patch or an
+/// explicit `X.Y.Z`.
+fn next_version(current: &str, part: &str) -> Result<String> {
+    let [major, minor, patch] = parse_version(current)?;
+    Ok(match part {
+        "major" => format!("{}.0.0", major + 1),
+        "minor" => format!("{}.{}.0", major, minor + 1),
+        "patch" => format!("{}.{}.{}", major, minor, patch + 1),
+        explicit => {
+            parse_version(explicit)?;
+            explicit.to_string()
+        }
+    })
+}
+
+fn parse_version(version: &str) -> Result<[u64; 3]> {
+    let numbers = version
+        .split('.')
+        .map(str::parse)
+        .collect::<std::result::Result<Vec<u64>, _>>()
+        .map_err(|_| format!("invalid version '{}'", version))?;
+    numbers
+        .try_into()
+        .map_err(|_| format!("version '{}' is not of the form X.Y.Z", version).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bumps_each_part() {
+        assert_eq!(next_version("1.2.3", "major").unwrap(), "2.0.0");
+        assert_eq!(next_version("1.2.3", "minor").unwrap(), "1.3.0");
+        assert_eq!(next_version("1.2.3", "patch").unwrap(), "1.2.4");
+        assert_eq!(next_version("1.2.3", "4.0.0").unwrap(), "4.0.0");
+    }
+
+    #[test]
+    fn rejects_invalid_versions() {
+        assert!(next_version("1.2.3", "next").is_err());
+        assert!(next_version("1.2", "patch").is_err());
+    }
+
+    #[test]
+    fn bumps_only_the_package_version() {
+        let manifest = "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n\n[dependencies]\nclap = { version = \"4.5\" }\n";
+        let (updated, old, new) = bump_manifest(manifest, "minor").unwrap();
+        assert_eq!((old.as_str(), new.as_str()), ("0.1.0", "0.2.0"));
+        assert!(updated.contains("version = \"0.2.0\""));
+        assert!(updated.contains("clap = { version = \"4.5\" }"));
+    }
+}
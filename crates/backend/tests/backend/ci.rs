@@ -0,0 +1,70 @@
+use crate::common::{config_with, rendered};
+use cli_codegen_backend::models::config::CliConfig;
+use serde_json::{Value, json};
+
+const TARGETS: [&str; 3] = ["GitHubActions", "GitLab", "Makefile"];
+
+/// A `todo` CLI with every CI target, depending on sw-cli through `dependency`.
+fn config(dependency: Value, xtask: bool) -> CliConfig {
+    config_with(
+        "todo",
+        json!({ "ci": TARGETS, "sw_cli_dependency": dependency, "xtask": xtask }),
+    )
+}
+
+#[test]
+fn every_target_formats_lints_and_tests() {
+    let config = config(json!("Standalone"), false);
+    let github = rendered(&config, ".github/workflows/ci.yml");
+    for step in [
+        "      - run: cargo fmt --check\n",
+        "      - run: cargo clippy --workspace --all-targets -- -D warnings\n",
+        "      - run: cargo test --workspace\n",
+        "      - run: cargo build --release --package todo\n",
+        "          path: target/release/todo\n",
+    ] {
+        assert!(github.contains(step), "{}\n{}", step, github);
+    }
+    let gitlab = rendered(&config, ".gitlab-ci.yml");
+    for step in [
+        "    - cargo fmt --check\n",
+        "    - cargo clippy --workspace --all-targets -- -D warnings\n",
+        "    - cargo test --workspace\n",
+        "    - cargo build --release --package todo\n",
+    ] {
+        assert!(gitlab.contains(step), "{}\n{}", step, gitlab);
+    }
+    let makefile = rendered(&config, "Makefile");
+    assert!(makefile.starts_with(".PHONY: all check fmt lint test release\n"));
+    assert!(makefile.contains("\ntest:\n\tcargo test --workspace\n"));
+    assert!(!makefile.contains("submodule"));
+}
+
+#[test]
+fn submodules_are_checked_out_first() {
+    let config = config(json!("Submodule"), false);
+    let github = rendered(&config, ".github/workflows/ci.yml");
+    assert_eq!(
+        github
+            .matches("      - uses: actions/checkout@v4\n        with:\n          submodules: recursive\n")
+            .count(),
+        2,
+        "{}",
+        github
+    );
+    let gitlab = rendered(&config, ".gitlab-ci.yml");
+    assert!(gitlab.contains("\n  GIT_SUBMODULE_STRATEGY: recursive\n"));
+    let makefile = rendered(&config, "Makefile");
+    assert!(makefile.contains("\nsubmodules:\n\tgit submodule update --init --recursive\n"));
+    assert!(makefile.contains("\ntest: submodules\n\tcargo test --workspace\n"));
+}
+
+#[test]
+fn releases_use_xtask_dist() {
+    let config = config(json!("Standalone"), true);
+    let github = rendered(&config, ".github/workflows/ci.yml");
+    assert!(github.contains("      - run: cargo xtask dist\n"));
+    assert!(github.contains("          path: target/dist\n"));
+    let makefile = rendered(&config, "Makefile");
+    assert!(makefile.contains("\nrelease:\n\tcargo xtask dist\n"));
+}
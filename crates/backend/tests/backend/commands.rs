@@ -0,0 +1,126 @@
+use crate::common::{config_with, rendered, validation_error};
+use cli_codegen_backend::codegen::generators::CodeGenerator;
+use serde_json::json;
+
+#[test]
+fn required_vec_arguments_are_required_by_clap() {
+    let config = config_with(
+        "files",
+        json!({
+            "layout": "Split",
+            "commands": [{
+                "name": "add",
+                "args": [
+                    { "name": "paths", "kind": "Positional", "multiple": true, "required": true },
+                    { "name": "tag", "multiple": true }
+                ]
+            }]
+        }),
+    );
+    let cli = rendered(&config, "src/cli.rs");
+    assert!(cli.contains("#[arg(required = true)]\n        paths: Vec<String>,"));
+    assert!(cli.contains("#[arg(long)]\n        tag: Vec<String>,"));
+
+    let tests = rendered(&config, "tests/cli.rs");
+    assert!(tests.contains("fn add_without_required_arguments_fails()"));
+    assert!(!tests.contains("fn add_runs()"));
+}
+
+#[test]
+fn keyword_argument_names_are_rejected() {
+    for scope in [
+        json!({ "args": [{ "name": "type" }] }),
+        json!({ "commands": [{ "name": "add", "args": [{ "name": "match" }] }] }),
+    ] {
+        let error = validation_error(&config_with("kw", scope));
+        assert!(error.contains("is a Rust keyword"), "{}", error);
+    }
+
+    // The flag can keep the keyword
+    let config = config_with(
+        "kw",
+        json!({ "args": [{ "name": "kind", "long": "type" }] }),
+    );
+    CodeGenerator::validate_config(&config).unwrap();
+}
+
+#[test]
+fn keyword_command_names_are_rejected() {
+    let error = validation_error(&config_with(
+        "kw",
+        json!({ "commands": [{ "name": "self" }] }),
+    ));
+    assert_eq!(error, "Command name 'self' is a Rust keyword");
+
+    let error = validation_error(&config_with(
+        "kw",
+        json!({ "layout": "CommandsDir", "commands": [{ "name": "type" }] }),
+    ));
+    assert!(
+        error.contains("cannot be used as a module name"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn command_names_of_the_same_variant_are_rejected() {
+    let error = validation_error(&config_with(
+        "dup",
+        json!({ "commands": [{ "name": "add-1" }, { "name": "add1" }] }),
+    ));
+    assert_eq!(error, "Duplicate command 'add1'");
+
+    let error = validation_error(&config_with(
+        "dup",
+        json!({ "commands": [{ "name": "help" }] }),
+    ));
+    assert_eq!(
+        error,
+        "Command name 'help' is reserved for clap's help subcommand"
+    );
+}
+
+#[test]
+fn argument_names_need_a_letter() {
+    for name in ["_", "__", "_1"] {
+        let error = validation_error(&config_with("us", json!({ "args": [{ "name": name }] })));
+        assert!(
+            error.contains("must be a snake_case identifier"),
+            "{}",
+            error
+        );
+    }
+}
+
+#[test]
+fn long_flags_must_be_plain_words() {
+    for long in ["A\"B", "two words", "-dash", ""] {
+        let error = validation_error(&config_with(
+            "long",
+            json!({ "args": [{ "name": "name", "long": long }] }),
+        ));
+        assert!(
+            error.contains("must be letters, digits, dashes"),
+            "{}",
+            error
+        );
+    }
+}
+
+#[test]
+fn value_names_are_escaped() {
+    let config = config_with(
+        "quote",
+        json!({
+            "layout": "Split",
+            "args": [{ "name": "name", "value_name": "A\"B\\C" }]
+        }),
+    );
+    let cli = rendered(&config, "src/cli.rs");
+    assert!(
+        cli.contains(r#"#[arg(long, value_name = "A\"B\\C")]"#),
+        "{}",
+        cli
+    );
+}
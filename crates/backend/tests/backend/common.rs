@@ -0,0 +1,91 @@
+//! Helpers shared by the integration tests.
+
+use cli_codegen_backend::codegen::generators::CodeGenerator;
+use cli_codegen_backend::models::config::CliConfig;
+use std::path::PathBuf;
+
+/// A scratch directory removed when dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// A fresh, empty directory unique to `name` and this test process.
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("cli-gen-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        TempDir(path)
+    }
+
+    /// The directory as a `&str`, as taken by the generator's entry points.
+    pub fn as_str(&self) -> &str {
+        self.0.to_str().unwrap()
+    }
+
+    /// `relative` inside the directory.
+    pub fn path(&self, relative: &str) -> PathBuf {
+        self.0.join(relative)
+    }
+
+    /// Content of the file at `relative`, if it exists.
+    pub fn read(&self, relative: &str) -> Option<String> {
+        std::fs::read_to_string(self.path(relative)).ok()
+    }
+
+    /// Delete the file at `relative`.
+    pub fn remove(&self, relative: &str) {
+        std::fs::remove_file(self.path(relative)).unwrap();
+    }
+
+    /// Write `content` to `relative`, creating parent directories.
+    pub fn write(&self, relative: &str, content: &str) {
+        let path = self.path(relative);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// A valid configuration named `name`, without commands or arguments.
+pub fn config(name: &str) -> CliConfig {
+    CliConfig {
+        name: name.to_string(),
+        ..CliConfig::default()
+    }
+}
+
+/// `config` parsed from a JSON object merged over `config(name)`.
+pub fn config_with(name: &str, overrides: serde_json::Value) -> CliConfig {
+    let mut document = serde_json::to_value(config(name)).unwrap();
+    for (key, value) in overrides.as_object().unwrap() {
+        document[key] = value.clone();
+    }
+    serde_json::from_value(document).unwrap()
+}
+
+/// Content of the file at `path` in the rendering of `config`.
+pub fn rendered(config: &CliConfig, path: &str) -> String {
+    CodeGenerator::render_files(config)
+        .unwrap()
+        .into_iter()
+        .find(|file| file.path == path)
+        .unwrap_or_else(|| panic!("{} was not rendered", path))
+        .content
+}
+
+/// The first validation error of `config`.
+pub fn validation_error(config: &CliConfig) -> String {
+    CodeGenerator::validate_config(config).unwrap_err()
+}
+
+/// The message of every validation error of `config`.
+pub fn validation_messages(config: &CliConfig) -> Vec<String> {
+    CodeGenerator::validation_errors(config)
+        .into_iter()
+        .map(|error| error.message)
+        .collect()
+}
@@ -0,0 +1,52 @@
+use crate::common::{config_with, rendered, validation_error};
+use serde_json::json;
+
+#[test]
+fn completions_are_printed_by_main_in_the_simple_architecture() {
+    let config = config_with("todo", json!({ "layout": "Split", "completions": true }));
+    let cli = rendered(&config, "src/cli.rs");
+    assert!(cli.contains(
+        "    /// Print shell completions to stdout\n    Completions {\n        /// Shell to generate completions for\n        shell: clap_complete::Shell,\n    },"
+    ));
+    let main = rendered(&config, "src/main.rs");
+    assert!(main.contains("if let Some(Commands::Completions { shell }) = &cli.command {"));
+    assert!(
+        main.contains(
+            "clap_complete::generate(*shell, &mut command, name, &mut std::io::stdout());"
+        )
+    );
+    assert!(rendered(&config, "Cargo.toml").contains("\nclap_complete = \"4.5\"\n"));
+
+    let help = rendered(&config, "src/long-help.txt");
+    assert!(help.contains("SHELL COMPLETIONS:\n"), "{}", help);
+    assert!(help.contains("    todo completions zsh > ~/.zfunc/_todo\n"));
+}
+
+#[test]
+fn completions_have_a_handler_in_the_dispatch_architecture() {
+    let config = config_with(
+        "todo",
+        json!({
+            "layout": "Split",
+            "architecture": "Dispatch",
+            "completions": true,
+            "commands": [{ "name": "add" }]
+        }),
+    );
+    let dispatch = rendered(&config, "src/dispatch.rs");
+    assert!(dispatch.contains("Some(Commands::Completions(command)) => command.handle(config),"));
+    assert!(dispatch.contains("impl CommandHandler for crate::config::CompletionsCommand {"));
+    assert!(dispatch.contains("clap_complete::generate(self.shell, &mut command, name"));
+}
+
+#[test]
+fn completions_reserve_the_command_name() {
+    let config = config_with(
+        "todo",
+        json!({ "completions": true, "commands": [{ "name": "completions" }] }),
+    );
+    assert_eq!(
+        validation_error(&config),
+        "Command name 'completions' is reserved when completions are enabled"
+    );
+}
@@ -0,0 +1,127 @@
+use crate::common::{TempDir, config_with, rendered};
+use cli_codegen_backend::codegen::generators::CodeGenerator;
+use cli_codegen_backend::models::config::CliConfig;
+use serde_json::{Value, json};
+
+const URL: &str = "https://example.com/sw-cli.git";
+
+/// A `todo` CLI in `layout` depending on sw-cli through `dependency`.
+fn config(layout: &str, dependency: Value) -> CliConfig {
+    config_with(
+        "todo",
+        json!({ "layout": layout, "sw_cli_url": URL, "sw_cli_dependency": dependency }),
+    )
+}
+
+fn paths(config: &CliConfig) -> Vec<String> {
+    CodeGenerator::render_files(config)
+        .unwrap()
+        .into_iter()
+        .map(|file| file.path)
+        .collect()
+}
+
+/// Assert that `Cargo.toml` uses `spec` for sw-cli, also in the build script.
+fn assert_dependency(config: &CliConfig, spec: &str) {
+    let manifest = rendered(config, "Cargo.toml");
+    let line = format!("\nsw-cli = {}\n", spec);
+    assert_eq!(manifest.matches(&line).count(), 2, "{}", manifest);
+    assert!(manifest.contains(&format!("[build-dependencies]{}", line)));
+}
+
+#[test]
+fn submodule_mode_adds_the_submodule() {
+    let config = config("Split", json!("Submodule"));
+    assert_dependency(&config, "{ path = \"lib/sw-cli\" }");
+    assert_eq!(
+        rendered(&config, ".gitmodules"),
+        format!(
+            "[submodule \"lib/sw-cli\"]\n\tpath = lib/sw-cli\n\turl = {}\n",
+            URL
+        )
+    );
+    let setup = rendered(&config, "scripts/setup.sh");
+    assert!(setup.contains(&format!("    git submodule add {} lib/sw-cli\n", URL)));
+}
+
+#[test]
+fn git_mode_pins_the_tag() {
+    let config = config("Split", json!({ "Git": { "rev": null, "tag": "v0.2.0" } }));
+    assert_dependency(
+        &config,
+        &format!("{{ git = \"{}\", tag = \"v0.2.0\" }}", URL),
+    );
+    assert!(!paths(&config).contains(&".gitmodules".to_string()));
+    let setup = rendered(&config, "scripts/setup.sh");
+    assert!(setup.contains("sw-cli will be fetched by cargo on the first build"));
+    assert!(!setup.contains("git submodule"));
+}
+
+#[test]
+fn path_mode_checks_the_checkout() {
+    let config = config("Split", json!({ "Path": { "path": "../sw-cli" } }));
+    assert_dependency(&config, "{ path = \"../sw-cli\" }");
+    assert!(!paths(&config).contains(&".gitmodules".to_string()));
+    let setup = rendered(&config, "scripts/setup.sh");
+    assert!(setup.contains("if [ ! -f \"../sw-cli/Cargo.toml\" ]; then\n"));
+}
+
+#[test]
+fn registry_mode_uses_the_version() {
+    let config = config("Split", json!({ "Registry": { "version": "0.1" } }));
+    assert_dependency(&config, "\"0.1\"");
+    assert!(!paths(&config).contains(&".gitmodules".to_string()));
+    let setup = rendered(&config, "scripts/setup.sh");
+    assert!(setup.contains("sw-cli will be fetched by cargo on the first build"));
+}
+
+#[test]
+fn vendored_mode_copies_the_text_files_of_the_checkout() {
+    let checkout = TempDir::new("vendor-source");
+    checkout.write("Cargo.toml", "[package]\nname = \"sw-cli\"\n");
+    checkout.write("src/lib.rs", "pub fn hello() {}\n");
+    checkout.write(".git/HEAD", "ref: refs/heads/main\n");
+    checkout.write("target/debug/stale", "stale\n");
+    std::fs::write(checkout.path("logo.png"), [0x89, b'P', b'N', b'G', 0xff]).unwrap();
+
+    let config = config(
+        "Split",
+        json!({ "Vendored": { "source": checkout.as_str() } }),
+    );
+    assert_dependency(&config, "{ path = \"lib/sw-cli\" }");
+    let vendored: Vec<String> = paths(&config)
+        .into_iter()
+        .filter(|path| path.starts_with("lib/sw-cli/"))
+        .collect();
+    assert_eq!(vendored, ["lib/sw-cli/Cargo.toml", "lib/sw-cli/src/lib.rs"]);
+    assert_eq!(
+        rendered(&config, "lib/sw-cli/src/lib.rs"),
+        "pub fn hello() {}\n"
+    );
+    assert!(!paths(&config).contains(&".gitmodules".to_string()));
+    let setup = rendered(&config, "scripts/setup.sh");
+    assert!(setup.contains("echo \"Using vendored sw-cli in lib/sw-cli\""));
+}
+
+#[test]
+fn workspace_members_inherit_the_dependency() {
+    let config = config("Workspace", json!({ "Path": { "path": "../sw-cli" } }));
+    let manifest = rendered(&config, "Cargo.toml");
+    assert!(
+        manifest.contains("[workspace.dependencies]\n")
+            && manifest.contains("\nsw-cli = { path = \"../sw-cli\" }\n"),
+        "{}",
+        manifest
+    );
+    for member in ["crates/todo/Cargo.toml", "crates/todo-core/Cargo.toml"] {
+        let manifest = rendered(&config, member);
+        assert!(
+            manifest.contains("\nsw-cli.workspace = true\n"),
+            "{}",
+            manifest
+        );
+        assert!(!manifest.contains("path = "), "{}", manifest);
+    }
+    let bin = rendered(&config, "crates/todo/Cargo.toml");
+    assert!(bin.contains("[build-dependencies]\nsw-cli.workspace = true"));
+}
@@ -0,0 +1,91 @@
+use crate::common::{config_with, rendered, validation_error};
+use cli_codegen_backend::models::config::CliConfig;
+use serde_json::json;
+
+/// A split `todo` CLI with `error_handling`, optionally sysexits, and a
+/// `not-found` exit code.
+fn config(error_handling: &str, sysexits: bool) -> CliConfig {
+    config_with(
+        "todo",
+        json!({
+            "layout": "Split",
+            "error_handling": error_handling,
+            "sysexits": sysexits,
+            "exit_codes": [{ "name": "not-found", "code": 3, "description": "Todo not found" }]
+        }),
+    )
+}
+
+#[test]
+fn long_help_lists_the_exit_codes() {
+    let help = rendered(&config("Thiserror", false), "src/long-help.txt");
+    assert!(
+        help.contains(
+            "    0 - Success\n    1 - Error occurred\n    2 - Invalid command-line usage\n    3 - Todo not found\n"
+        ),
+        "{}",
+        help
+    );
+    let help = rendered(&config("Thiserror", true), "src/long-help.txt");
+    assert!(help.contains("     3 - Todo not found\n    64 - Command line usage error\n"));
+    assert!(help.contains("    78 - Configuration error"), "{}", help);
+    assert!(!help.contains("Invalid command-line usage"));
+}
+
+#[test]
+fn thiserror_has_a_variant_per_exit_code() {
+    let error = rendered(&config("Thiserror", false), "src/error.rs");
+    assert!(error.contains("    /// Todo not found\n    NotFound = 3,\n"));
+    assert!(
+        error.contains("    /// Todo not found\n    #[error(\"{0}\")]\n    NotFound(String),\n")
+    );
+    assert!(error.contains("            Error::NotFound(_) => Exit::NotFound,\n"));
+    assert!(error.contains("            Error::Io(_) => Exit::Failure,\n"));
+    assert!(!error.contains("parse_args"));
+    let manifest = rendered(&config("Thiserror", false), "Cargo.toml");
+    assert!(manifest.contains("\nthiserror = \"2\"\n"));
+}
+
+#[test]
+fn anyhow_finds_the_exit_code_in_the_causes() {
+    let error = rendered(&config("Anyhow", false), "src/error.rs");
+    assert!(error.contains("pub use anyhow::{Error, Result};\n"));
+    assert!(error.contains("if let Some(error) = cause.downcast_ref::<ExitError>() {\n"));
+    assert!(!error.contains("cause.is::<std::io::Error>()"));
+    let error = rendered(&config("Anyhow", true), "src/error.rs");
+    assert!(error.contains(
+        "        if cause.is::<std::io::Error>() {\n            return Exit::IoErr.into();\n"
+    ));
+    let manifest = rendered(&config("Anyhow", false), "Cargo.toml");
+    assert!(manifest.contains("\nanyhow = \"1\"\n"));
+}
+
+#[test]
+fn sysexits_parses_with_ex_usage() {
+    let config = config("Thiserror", true);
+    let error = rendered(&config, "src/error.rs");
+    assert!(error.contains("pub fn parse_args<P: clap::Parser>() -> P {\n"));
+    assert!(error.contains("            Exit::Usage as i32\n"));
+    assert!(error.contains("            Error::Io(_) => Exit::IoErr,\n"));
+    let main = rendered(&config, "src/main.rs");
+    assert!(main.contains("error::parse_args::<Cli>()"), "{}", main);
+    assert!(!main.contains("use clap::Parser;"));
+}
+
+#[test]
+fn main_maps_errors_to_exit_codes() {
+    let main = rendered(&config("Anyhow", false), "src/main.rs");
+    assert!(main.contains("fn main() -> ExitCode {\n"), "{}", main);
+    assert!(main.contains(
+        "            eprintln!(\"Error: {:#}\", e);\n            error::exit_code(&e)\n"
+    ));
+}
+
+#[test]
+fn exit_codes_need_an_error_scaffold() {
+    let config = config_with("todo", json!({ "sysexits": true }));
+    assert_eq!(
+        validation_error(&config),
+        "Exit codes need an error handling scaffold"
+    );
+}
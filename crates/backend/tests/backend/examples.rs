@@ -0,0 +1,43 @@
+use crate::common::{config_with, rendered};
+use serde_json::json;
+
+/// The `src/cli.rs` of a project whose `add` command has one example.
+fn cli_with_example(command_line: &str) -> String {
+    let config = config_with(
+        "todo",
+        json!({
+            "layout": "Split",
+            "commands": [{
+                "name": "add",
+                "args": [
+                    { "name": "item", "kind": "Positional" },
+                    { "name": "with", "kind": "Flag" }
+                ],
+                "examples": [{ "command_line": command_line, "description": "Add one" }]
+            }]
+        }),
+    );
+    rendered(&config, "src/cli.rs")
+}
+
+#[test]
+fn single_argument_attributes_stay_on_one_line_up_to_the_line_width() {
+    // The argument is wider than 70 columns, the line is not wider than 100
+    let cli = cli_with_example("add some-item-name --with");
+    let line = cli
+        .lines()
+        .find(|line| line.contains("after_long_help"))
+        .unwrap();
+    assert!(
+        line.starts_with("    #[command(after_long_help = "),
+        "{}",
+        line
+    );
+    assert!(line.len() > 80 && line.len() <= 100, "{}", line);
+}
+
+#[test]
+fn single_argument_attributes_wider_than_the_line_break() {
+    let cli = cli_with_example("add a-much-longer-example-line --with and-more-words-here");
+    assert!(cli.contains("    #[command(\n        after_long_help = \"Examples:"));
+}
@@ -0,0 +1,133 @@
+use crate::common::{TempDir, config_with};
+use cli_codegen_backend::codegen::generators::CodeGenerator;
+use cli_codegen_backend::import::ClapImporter;
+use cli_codegen_backend::models::config::{ArgKind, GlobalOption};
+use serde_json::json;
+
+/// A crate named `tool` whose `src/main.rs` is `source`.
+fn crate_with(name: &str, source: &str) -> TempDir {
+    let dir = TempDir::new(name);
+    dir.write(
+        "Cargo.toml",
+        "[package]\nname = \"tool\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    );
+    dir.write("src/main.rs", source);
+    dir
+}
+
+#[test]
+fn bare_default_value_t_is_optional() {
+    let dir = crate_with(
+        "import-default-value-t",
+        r#"
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[arg(long, default_value_t)]
+            retries: u32,
+        }
+        "#,
+    );
+    let config = ClapImporter::import(dir.as_str()).unwrap();
+    let retries = &config.args[0];
+    assert_eq!(retries.name, "retries");
+    assert_eq!(retries.kind, ArgKind::Option);
+    assert!(!retries.required);
+    assert_eq!(retries.default_value, None);
+}
+
+#[test]
+fn unreadable_settings_do_not_fail_the_import() {
+    let dir = crate_with(
+        "import-unreadable",
+        r#"
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[arg(long, value_name)]
+            name: Option<String>,
+            #[arg(short, long)]
+            force: bool,
+        }
+        "#,
+    );
+    let config = ClapImporter::import(dir.as_str()).unwrap();
+    let names: Vec<&str> = config.args.iter().map(|arg| arg.name.as_str()).collect();
+    assert_eq!(names, ["name", "force"]);
+}
+
+#[test]
+fn counted_verbose_becomes_the_verbosity_option() {
+    let dir = crate_with(
+        "import-verbosity",
+        r#"
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[arg(short, long, action = clap::ArgAction::Count)]
+            verbose: u8,
+            #[arg(short, long)]
+            quiet: bool,
+            #[arg(long)]
+            target: Option<String>,
+        }
+        "#,
+    );
+    let config = ClapImporter::import(dir.as_str()).unwrap();
+    assert_eq!(
+        config.global_options,
+        [GlobalOption::Verbosity, GlobalOption::Quiet]
+    );
+    assert_eq!(config.args.len(), 1);
+    assert_eq!(config.args[0].name, "target");
+    CodeGenerator::validate_config(&config).unwrap();
+}
+
+#[test]
+fn generated_projects_import_with_their_global_options() {
+    let options = [
+        GlobalOption::Verbosity,
+        GlobalOption::Quiet,
+        GlobalOption::DryRun,
+        GlobalOption::Color,
+        GlobalOption::ConfigFile,
+        GlobalOption::Output,
+        GlobalOption::NoInput,
+    ];
+    let config = config_with(
+        "roundtrip",
+        json!({
+            "layout": "Split",
+            "sw_cli_dependency": "Standalone",
+            "global_options": options,
+            "commands": [{ "name": "add", "args": [{ "name": "title", "kind": "Positional" }] }]
+        }),
+    );
+    let dir = TempDir::new("import-roundtrip");
+    CodeGenerator::generate_files(&config, dir.as_str()).unwrap();
+
+    let imported = ClapImporter::import(dir.as_str()).unwrap();
+    assert_eq!(imported.global_options, options);
+    assert!(imported.args.is_empty());
+    assert_eq!(imported.commands[0].name, "add");
+    assert_eq!(imported.commands[0].args[0].name, "title");
+    CodeGenerator::validate_config(&imported).unwrap();
+}
+
+#[test]
+fn raw_identifiers_keep_their_flags() {
+    let dir = crate_with(
+        "import-raw",
+        r#"
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[arg(long)]
+            r#type: Option<String>,
+            r#match: String,
+        }
+        "#,
+    );
+    let config = ClapImporter::import(dir.as_str()).unwrap();
+    assert_eq!(config.args[0].name, "type_");
+    assert_eq!(config.args[0].long.as_deref(), Some("type"));
+    assert_eq!(config.args[1].name, "match_");
+    assert_eq!(config.args[1].value_name.as_deref(), Some("MATCH"));
+    CodeGenerator::validate_config(&config).unwrap();
+}
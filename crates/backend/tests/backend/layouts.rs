@@ -0,0 +1,214 @@
+use crate::common::{TempDir, config_with, validation_error};
+use cli_codegen_backend::codegen::generators::{CodeGenerator, GeneratedFile};
+use cli_codegen_backend::models::config::CliConfig;
+use serde_json::json;
+use std::process::Command;
+
+/// Every valid layout and architecture.
+const COMBINATIONS: [(&str, &str); 6] = [
+    ("SingleFile", "Simple"),
+    ("Split", "Simple"),
+    ("Split", "Dispatch"),
+    ("CommandsDir", "Simple"),
+    ("Workspace", "Simple"),
+    ("Workspace", "Dispatch"),
+];
+
+/// A standalone `todo` CLI with two commands in `layout` and `architecture`.
+fn config(layout: &str, architecture: &str) -> CliConfig {
+    config_with(
+        "todo",
+        json!({
+            "layout": layout,
+            "architecture": architecture,
+            "sw_cli_dependency": "Standalone",
+            "args": [{ "name": "file", "short": "f", "value_type": "PathBuf" }],
+            "commands": [
+                { "name": "add", "args": [{ "name": "text", "kind": "Positional", "required": true }] },
+                { "name": "clear-all" }
+            ]
+        }),
+    )
+}
+
+/// `config(layout, architecture)` with the optional scaffolds turned on, as
+/// built by the smoke test; `variant` alternates between their flavors.
+fn smoke_config(layout: &str, architecture: &str, variant: usize) -> CliConfig {
+    let mut document = serde_json::to_value(config(layout, architecture)).unwrap();
+    document["completions"] = json!(true);
+    // Man pages need a library crate
+    document["man_pages"] = json!(layout != "SingleFile");
+    document["xtask"] = json!(variant.is_multiple_of(2));
+    document["ci"] = json!(["GitHubActions", "GitLab", "Makefile"]);
+    let logging = ["Tracing", "EnvLogger", "None"][variant % 3];
+    document["logging"] = json!(logging);
+    document["log_json"] = json!(logging == "Tracing");
+    document["log_file"] = json!(logging != "None");
+    // Offset from the logging backends to vary their pairings
+    let error_handling = ["Thiserror", "Anyhow", "None"][(variant + 1) % 3];
+    document["error_handling"] = json!(error_handling);
+    if error_handling != "None" {
+        document["sysexits"] = json!(true);
+        document["exit_codes"] =
+            json!([{ "name": "not-found", "code": 3, "description": "Todo not found" }]);
+    }
+    serde_json::from_value(document).unwrap()
+}
+
+fn rust_files(layout: &str, architecture: &str) -> Vec<GeneratedFile> {
+    CodeGenerator::render_files(&config(layout, architecture))
+        .unwrap()
+        .into_iter()
+        .filter(|file| file.path.ends_with(".rs"))
+        .collect()
+}
+
+fn rust_paths(layout: &str, architecture: &str) -> Vec<String> {
+    let mut paths: Vec<String> = rust_files(layout, architecture)
+        .into_iter()
+        .map(|file| file.path)
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+fn every_combination_renders_valid_rust() {
+    for (layout, architecture) in COMBINATIONS {
+        for file in rust_files(layout, architecture) {
+            if let Err(e) = syn::parse_file(&file.content) {
+                panic!("{} of {} {}: {}", file.path, layout, architecture, e);
+            }
+        }
+    }
+}
+
+#[test]
+fn single_file_keeps_the_parser_in_main() {
+    assert_eq!(
+        rust_paths("SingleFile", "Simple"),
+        ["build.rs", "src/main.rs", "src/version.rs", "tests/cli.rs"]
+    );
+}
+
+#[test]
+fn split_moves_the_parser_to_a_library() {
+    assert_eq!(
+        rust_paths("Split", "Simple"),
+        [
+            "build.rs",
+            "src/cli.rs",
+            "src/lib.rs",
+            "src/main.rs",
+            "src/version.rs",
+            "tests/cli.rs"
+        ]
+    );
+    assert_eq!(
+        rust_paths("Split", "Dispatch"),
+        [
+            "build.rs",
+            "src/config.rs",
+            "src/dispatch.rs",
+            "src/lib.rs",
+            "src/main.rs",
+            "src/version.rs",
+            "tests/cli.rs"
+        ]
+    );
+}
+
+#[test]
+fn commands_dir_has_a_module_per_command() {
+    assert_eq!(
+        rust_paths("CommandsDir", "Simple"),
+        [
+            "build.rs",
+            "src/cli.rs",
+            "src/commands/add.rs",
+            "src/commands/clear_all.rs",
+            "src/commands/mod.rs",
+            "src/lib.rs",
+            "src/main.rs",
+            "src/version.rs",
+            "tests/cli.rs"
+        ]
+    );
+}
+
+#[test]
+fn workspace_splits_binary_and_core_crates() {
+    assert_eq!(
+        rust_paths("Workspace", "Simple"),
+        [
+            "crates/todo-core/src/cli.rs",
+            "crates/todo-core/src/lib.rs",
+            "crates/todo/build.rs",
+            "crates/todo/src/main.rs",
+            "crates/todo/src/version.rs",
+            "crates/todo/tests/cli.rs"
+        ]
+    );
+    assert_eq!(
+        rust_paths("Workspace", "Dispatch"),
+        [
+            "crates/todo-core/src/config.rs",
+            "crates/todo-core/src/dispatch.rs",
+            "crates/todo-core/src/lib.rs",
+            "crates/todo/build.rs",
+            "crates/todo/src/main.rs",
+            "crates/todo/src/version.rs",
+            "crates/todo/tests/cli.rs"
+        ]
+    );
+}
+
+#[test]
+fn dispatch_needs_a_library_crate() {
+    for layout in ["SingleFile", "CommandsDir"] {
+        assert_eq!(
+            validation_error(&config(layout, "Dispatch")),
+            "The dispatch architecture needs the split or workspace layout"
+        );
+    }
+}
+
+#[test]
+fn commands_dir_needs_commands_with_module_names() {
+    let empty = config_with("todo", json!({ "layout": "CommandsDir" }));
+    assert_eq!(
+        validation_error(&empty),
+        "The commands-dir layout needs at least one command"
+    );
+
+    let keyword = config_with(
+        "todo",
+        json!({ "layout": "CommandsDir", "commands": [{ "name": "match" }] }),
+    );
+    assert_eq!(
+        validation_error(&keyword),
+        "Command name 'match' cannot be used as a module name in the commands-dir layout"
+    );
+}
+
+/// Checks, lints and tests a project of every combination with `make check`.
+/// Needs the dependencies of the generated projects, from the network or the
+/// local registry cache: `cargo test -- --ignored`.
+#[test]
+#[ignore]
+fn every_combination_passes_its_checks() {
+    let target = std::env::temp_dir().join("cli-gen-smoke-target");
+    for (variant, (layout, architecture)) in COMBINATIONS.into_iter().enumerate() {
+        let dir = TempDir::new(&format!("smoke-{}-{}", layout, architecture));
+        let config = smoke_config(layout, architecture, variant);
+        CodeGenerator::generate_files(&config, dir.as_str()).unwrap();
+        // The formatting, lints and tests of the generated CI
+        let status = Command::new("make")
+            .arg("check")
+            .env("CARGO_TARGET_DIR", &target)
+            .current_dir(dir.as_str())
+            .status()
+            .unwrap();
+        assert!(status.success(), "{} {}", layout, architecture);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::common::{config_with, rendered, validation_error};
+use cli_codegen_backend::models::config::CliConfig;
+use serde_json::{Value, json};
+
+/// A split `todo` CLI with the logging `overrides`.
+fn config(overrides: Value) -> CliConfig {
+    let mut document = json!({ "layout": "Split" });
+    for (key, value) in overrides.as_object().unwrap() {
+        document[key] = value.clone();
+    }
+    config_with("todo", document)
+}
+
+#[test]
+fn tracing_installs_a_subscriber() {
+    let config = config(json!({ "logging": "Tracing" }));
+    let logging = rendered(&config, "src/logging.rs");
+    assert!(logging.contains("use tracing_subscriber::EnvFilter;\n"));
+    assert!(
+        logging
+            .contains("pub fn init(quiet: bool, verbosity: u8) -> Result<(), Box<dyn Error>> {\n")
+    );
+    assert!(logging.contains(".with_writer(std::io::stderr);\n    subscriber.try_init()"));
+    let manifest = rendered(&config, "Cargo.toml");
+    assert!(manifest.contains(
+        "tracing = \"0.1\"\ntracing-subscriber = { version = \"0.3\", features = [\"env-filter\"] }\n"
+    ));
+    let main = rendered(&config, "src/main.rs");
+    assert!(main.contains("mod logging;\n"), "{}", main);
+    assert!(main.contains("    if let Err(e) = logging::init(false, u8::from(cli.verbose)) {\n"));
+}
+
+#[test]
+fn env_logger_installs_a_logger() {
+    let config = config(json!({ "logging": "EnvLogger" }));
+    let logging = rendered(&config, "src/logging.rs");
+    assert!(logging.contains("let mut builder = env_logger::Builder::new();\n"));
+    assert!(logging.contains("        .parse_default_env();\n    builder.try_init()?;\n"));
+    let manifest = rendered(&config, "Cargo.toml");
+    assert!(manifest.contains("env_logger = \"0.11\"\nlog = \"0.4\"\n"));
+}
+
+#[test]
+fn log_json_enables_the_json_formatter() {
+    let config = config(json!({ "logging": "Tracing", "log_json": true }));
+    let logging = rendered(&config, "src/logging.rs");
+    assert!(logging.contains(".with_writer(std::io::stderr)\n        .json();\n"));
+    assert!(rendered(&config, "Cargo.toml").contains("features = [\"env-filter\", \"json\"]"));
+}
+
+#[test]
+fn log_file_adds_an_option_and_appends_to_it() {
+    let tracing = config(json!({ "logging": "Tracing", "log_file": true }));
+    let logging = rendered(&tracing, "src/logging.rs");
+    assert!(logging.contains("use std::sync::Mutex;\n"));
+    assert!(logging.contains("verbosity: u8, log_file: Option<&Path>)"));
+    assert!(logging.contains(".with_writer(Mutex::new(file))\n"));
+    let main = rendered(&tracing, "src/main.rs");
+    assert!(main.contains("cli.log_file.as_deref()"), "{}", main);
+    let cli = rendered(&tracing, "src/cli.rs");
+    assert!(
+        cli.contains(
+            "    #[arg(long, value_name = \"FILE\")]\n    pub log_file: Option<std::path::PathBuf>,"
+        ),
+        "{}",
+        cli
+    );
+
+    let env_logger = config(json!({ "logging": "EnvLogger", "log_file": true }));
+    let logging = rendered(&env_logger, "src/logging.rs");
+    assert!(logging.contains("builder.target(env_logger::Target::Pipe(Box::new(file)));\n"));
+    assert!(!logging.contains("Mutex"));
+}
+
+#[test]
+fn log_options_need_their_backend() {
+    assert_eq!(
+        validation_error(&config(json!({ "log_file": true }))),
+        "JSON logs and log files need a logging backend"
+    );
+    assert_eq!(
+        validation_error(&config(json!({ "logging": "EnvLogger", "log_json": true }))),
+        "JSON logs need the tracing backend"
+    );
+}
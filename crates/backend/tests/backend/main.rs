@@ -0,0 +1,23 @@
+//! Integration tests of the generator library.
+
+mod ci;
+mod commands;
+mod common;
+mod completions;
+mod dependency;
+mod errors;
+mod examples;
+mod import;
+mod layouts;
+mod logging;
+mod man;
+mod plan;
+mod readme;
+mod relations;
+mod schema;
+mod settings;
+mod snapshots;
+mod update;
+mod validation;
+mod value_enums;
+mod xtask;
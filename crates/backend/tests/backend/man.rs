@@ -0,0 +1,76 @@
+use crate::common::{config_with, rendered, validation_error};
+use serde_json::json;
+
+#[test]
+fn undocumented_env_args_are_listed_in_the_environment_section() {
+    let config = config_with(
+        "envy",
+        json!({
+            "layout": "Split",
+            "man_pages": true,
+            "args": [{ "name": "token", "env": true }]
+        }),
+    );
+    let mangen = rendered(&config, "examples/mangen.rs");
+    assert!(mangen.contains("page.extend_from_slice(environment_section(command).as_bytes());"));
+    assert!(
+        mangen.contains("let help = arg.get_help().map(ToString::to_string).unwrap_or_default();"),
+        "{}",
+        mangen
+    );
+    assert!(!mangen.contains("arg.get_help()?"));
+}
+
+#[test]
+fn split_renders_a_mangen_example() {
+    let config = config_with(
+        "todo",
+        json!({
+            "layout": "Split",
+            "man_pages": true,
+            "short_description": "Keep \"todos\"",
+            "commands": [{ "name": "add" }]
+        }),
+    );
+    let mangen = rendered(&config, "examples/mangen.rs");
+    assert!(mangen.contains("use todo::cli::Cli;\n"), "{}", mangen);
+    assert!(mangen.contains("const ABOUT: &str = \"Keep \\\"todos\\\"\";\n"));
+    assert!(mangen.contains("let mut command = Cli::command()\n"));
+    assert!(mangen.contains("for subcommand in command.get_subcommands() {\n"));
+    assert!(mangen.contains(".control(\"SH\", [\"LICENSE\"])"));
+    assert!(mangen.contains(".control(\"SH\", [\"REPOSITORY\"])"));
+    let manifest = rendered(&config, "Cargo.toml");
+    assert!(
+        manifest.contains("predicates = \"3\"\nclap_mangen = \"0.2\"\n"),
+        "{}",
+        manifest
+    );
+    let readme = rendered(&config, "README.md");
+    assert!(readme.contains("cargo run --package todo --example mangen -- target/man\n"));
+}
+
+#[test]
+fn workspace_renders_mangen_in_the_core_crate() {
+    let config = config_with(
+        "todo",
+        json!({ "layout": "Workspace", "architecture": "Dispatch", "man_pages": true }),
+    );
+    let mangen = rendered(&config, "crates/todo-core/examples/mangen.rs");
+    assert!(
+        mangen.contains("use todo_core::config::CliConfig;\n"),
+        "{}",
+        mangen
+    );
+    let core = rendered(&config, "crates/todo-core/Cargo.toml");
+    assert!(core.contains("[dev-dependencies]\nclap_mangen = \"0.2\""));
+    assert!(!rendered(&config, "Cargo.toml").contains("clap_mangen"));
+}
+
+#[test]
+fn single_file_cannot_render_man_pages() {
+    let config = config_with("todo", json!({ "layout": "SingleFile", "man_pages": true }));
+    assert_eq!(
+        validation_error(&config),
+        "Man pages need a library crate; use a layout other than single-file"
+    );
+}
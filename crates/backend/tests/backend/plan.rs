@@ -0,0 +1,151 @@
+use crate::common::{TempDir, config, config_with};
+use cli_codegen_backend::codegen::generators::{CodeGenerator, GeneratedFile};
+use cli_codegen_backend::codegen::plan::{FileAction, PlannedFile};
+use cli_codegen_backend::models::manifest::MANIFEST_FILE;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::os::unix::fs::PermissionsExt;
+
+fn planned(dir: &TempDir) -> Vec<PlannedFile> {
+    CodeGenerator::plan_files(&config("todo"), dir.as_str()).unwrap()
+}
+
+fn action(plan: &[PlannedFile], path: &str) -> FileAction {
+    plan.iter()
+        .find(|planned| planned.file.path == path)
+        .unwrap_or_else(|| panic!("{} is not planned", path))
+        .action
+}
+
+fn planned_file(existing: Option<&str>, existing_mode: Option<u32>) -> PlannedFile {
+    PlannedFile {
+        file: GeneratedFile {
+            path: "src/main.rs".to_string(),
+            content: "fn main() {\n    run();\n}\n".to_string(),
+            mode: 0o644,
+        },
+        action: match existing_mode {
+            None => FileAction::Create,
+            Some(_) => FileAction::Modify,
+        },
+        existing: existing.map(str::to_string),
+        existing_mode,
+    }
+}
+
+#[test]
+fn files_missing_from_the_output_are_created() {
+    let dir = TempDir::new("plan-create");
+    let plan = planned(&dir);
+    assert!(
+        plan.iter()
+            .all(|planned| planned.action == FileAction::Create)
+    );
+    assert!(plan.iter().all(|planned| planned.existing.is_none()));
+}
+
+#[test]
+fn generated_files_are_unchanged_and_edited_ones_modified() {
+    let dir = TempDir::new("plan-modify");
+    CodeGenerator::generate_files(&config("todo"), dir.as_str()).unwrap();
+    dir.write("README.md", "Local notes\n");
+
+    let plan = planned(&dir);
+    assert_eq!(action(&plan, "README.md"), FileAction::Modify);
+    assert_eq!(action(&plan, "Cargo.toml"), FileAction::Unchanged);
+    // Local edits leave the rendering, and so the manifest, as they were
+    assert_eq!(action(&plan, MANIFEST_FILE), FileAction::Unchanged);
+    let unchanged = plan
+        .iter()
+        .filter(|planned| planned.action == FileAction::Unchanged);
+    assert!(unchanged.clone().count() > 10);
+    assert!(unchanged.clone().all(|planned| planned.diff().is_none()));
+}
+
+#[test]
+fn the_manifest_records_the_hash_of_every_file() {
+    let dir = TempDir::new("plan-manifest");
+    CodeGenerator::generate_files(&config("todo"), dir.as_str()).unwrap();
+    let manifest = CodeGenerator::read_manifest(dir.as_str()).unwrap();
+
+    let files = CodeGenerator::render_files(&config("todo")).unwrap();
+    assert_eq!(manifest.files.len(), files.len());
+    let cargo = manifest
+        .files
+        .iter()
+        .find(|file| file.path == "Cargo.toml")
+        .unwrap();
+    let content = dir.read("Cargo.toml").unwrap();
+    assert_eq!(
+        cargo.sha256,
+        format!("{:x}", Sha256::digest(content.as_bytes()))
+    );
+    assert_eq!(cargo.mode, 0o644);
+    let setup = manifest
+        .files
+        .iter()
+        .find(|file| file.path == "scripts/setup.sh")
+        .unwrap();
+    assert_eq!(setup.mode, 0o755);
+}
+
+#[test]
+fn regenerating_keeps_the_timestamp_until_the_files_change() {
+    let dir = TempDir::new("plan-timestamp");
+    CodeGenerator::generate_files(&config("todo"), dir.as_str()).unwrap();
+    let generated_at = CodeGenerator::read_manifest(dir.as_str())
+        .unwrap()
+        .generated_at;
+
+    CodeGenerator::generate_files(&config("todo"), dir.as_str()).unwrap();
+    let manifest = CodeGenerator::read_manifest(dir.as_str()).unwrap();
+    assert_eq!(manifest.generated_at, generated_at);
+
+    let changed = config_with("todo", json!({ "short_description": "Track todos" }));
+    let plan = CodeGenerator::plan_files(&changed, dir.as_str()).unwrap();
+    assert_eq!(action(&plan, MANIFEST_FILE), FileAction::Modify);
+    let manifest = plan
+        .iter()
+        .find(|planned| planned.file.path == MANIFEST_FILE)
+        .unwrap();
+    assert!(!manifest.file.content.contains(&generated_at));
+}
+
+#[test]
+fn mode_changes_alone_modify_a_file() {
+    let dir = TempDir::new("plan-mode");
+    CodeGenerator::generate_files(&config("todo"), dir.as_str()).unwrap();
+    let path = dir.path("Cargo.toml");
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).unwrap();
+
+    let plan = planned(&dir);
+    let planned = plan
+        .iter()
+        .find(|planned| planned.file.path == "Cargo.toml")
+        .unwrap();
+    assert_eq!(planned.action, FileAction::Modify);
+    assert_eq!(planned.existing_mode, Some(0o600));
+    assert_eq!(
+        planned.diff().unwrap(),
+        "old mode 600\nnew mode 644\n--- a/Cargo.toml\n+++ b/Cargo.toml\n"
+    );
+}
+
+#[test]
+fn new_files_diff_from_dev_null() {
+    assert_eq!(
+        planned_file(None, None).diff().unwrap(),
+        "--- /dev/null\n+++ b/src/main.rs\n@@ -0,0 +1,3 @@\n+fn main() {\n+    run();\n+}\n"
+    );
+}
+
+#[test]
+fn modified_files_diff_from_their_content() {
+    let diff = planned_file(Some("fn main() {\n    todo!();\n}\n"), Some(0o755))
+        .diff()
+        .unwrap();
+    assert_eq!(
+        diff,
+        "old mode 755\nnew mode 644\n--- a/src/main.rs\n+++ b/src/main.rs\n@@ -1,3 +1,3 @@\n fn main() {\n-    todo!();\n+    run();\n }\n"
+    );
+}
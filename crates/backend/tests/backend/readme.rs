@@ -0,0 +1,113 @@
+use crate::common::{config_with, rendered};
+use cli_codegen_backend::models::config::CliConfig;
+use serde_json::{Value, json};
+
+/// A `todo` CLI with an option, two commands and an example.
+fn todo() -> CliConfig {
+    config_with(
+        "todo",
+        json!({
+            "layout": "Workspace",
+            "short_description": "Keep todos",
+            "long_description": "Stores them in a plain file.",
+            "args": [{ "name": "file", "short": "f", "value_type": "PathBuf", "description": "Todo file" }],
+            "commands": [
+                {
+                    "name": "add",
+                    "description": "Add a todo",
+                    "args": [{ "name": "text", "kind": "Positional", "required": true, "description": "Todo text" }]
+                },
+                { "name": "clear-all" }
+            ],
+            "examples": [{ "command_line": "--file work.txt add 'Ship it'", "description": "Add to another file" }]
+        }),
+    )
+}
+
+/// The `## Building` section of a `todo` CLI depending on sw-cli through
+/// `dependency`.
+fn building(dependency: Value, xtask: bool) -> String {
+    let config = config_with(
+        "todo",
+        json!({ "sw_cli_dependency": dependency, "xtask": xtask }),
+    );
+    let readme = rendered(&config, "README.md");
+    let start = readme.find("## Building\n").unwrap();
+    let end = readme.find("## Usage\n").unwrap();
+    readme[start..end].to_string()
+}
+
+#[test]
+fn usage_lists_the_options_and_commands() {
+    let readme = rendered(&todo(), "README.md");
+    assert!(readme.starts_with("# todo\n\nKeep todos\n\nStores them in a plain file.\n"));
+    assert!(readme.contains("```text\ntodo [OPTIONS] [COMMAND]\n```\n"));
+    assert!(
+        readme.contains("| `-f, --file <FILE>` | Todo file |\n"),
+        "{}",
+        readme
+    );
+    assert!(
+        readme.contains("| `-V, --version` | Print version information with build metadata |\n")
+    );
+}
+
+#[test]
+fn commands_have_a_usage_line_and_arguments() {
+    let readme = rendered(&todo(), "README.md");
+    assert!(readme.contains(
+        "### `add`\n\nAdd a todo\n\n```text\ntodo add <TEXT>\n```\n\n\
+         | Argument | Description |\n|----------|-------------|\n| `<TEXT>` | Todo text |\n"
+    ));
+    assert!(readme.contains("### `clear-all`\n\n```text\ntodo clear-all\n```\n"));
+}
+
+#[test]
+fn examples_cover_every_command() {
+    let readme = rendered(&todo(), "README.md");
+    assert!(readme.contains(
+        "# Add to another file\ntodo --file work.txt add 'Ship it'\n\n\
+         # Add a todo\ntodo add <TEXT>\n\ntodo clear-all\n```\n"
+    ));
+}
+
+#[test]
+fn building_installs_the_binary_crate() {
+    let readme = rendered(&todo(), "README.md");
+    assert!(readme.contains("```bash\ncargo install --path crates/todo\n```\n"));
+    assert!(
+        building(json!("Standalone"), false).contains("```bash\ncargo install --path .\n```\n")
+    );
+}
+
+#[test]
+fn building_follows_the_dependency_mode() {
+    assert!(building(json!("Submodule"), false).contains(
+        "sw-cli is a git submodule at `lib/sw-cli`. Clone with `--recursive`, or let\n\
+         `./scripts/setup.sh` add and initialize it.\n\n\
+         ```bash\n./scripts/setup.sh\n./scripts/build.sh\n```\n"
+    ));
+    assert!(
+        building(json!({ "Git": { "tag": "v0.2.0", "rev": null } }), false)
+            .contains("sw-cli is a git dependency that cargo fetches on the first build.\n")
+    );
+    assert!(
+        building(json!({ "Registry": { "version": "0.1" } }), false)
+            .contains("sw-cli is fetched from crates.io on the first build.\n")
+    );
+    assert!(
+        building(json!({ "Path": { "path": "../sw-cli" } }), false).contains(
+            "The build expects an sw-cli checkout at `../sw-cli`, relative to this directory.\n"
+        )
+    );
+    assert!(
+        building(json!("Standalone"), false).contains("The project only depends on crates.io.\n")
+    );
+}
+
+#[test]
+fn building_uses_xtask_when_enabled() {
+    let section = building(json!("Standalone"), true);
+    assert!(section.contains("```bash\ncargo xtask setup\ncargo xtask build\n```\n"));
+    assert!(!section.contains("scripts/"));
+}
@@ -0,0 +1,173 @@
+use crate::common::{config_with, validation_messages};
+use serde_json::{Value, json};
+
+/// Validation errors of an `add` command with `args` and `groups`.
+fn errors(args: Value, groups: Value) -> Vec<String> {
+    let config = config_with(
+        "todo",
+        json!({ "commands": [{ "name": "add", "args": args, "groups": groups }] }),
+    );
+    validation_messages(&config)
+}
+
+fn arg_errors(args: Value) -> Vec<String> {
+    errors(args, json!([]))
+}
+
+fn group_errors(groups: Value) -> Vec<String> {
+    errors(json!([{ "name": "json" }, { "name": "yaml" }]), groups)
+}
+
+#[test]
+fn relations_to_arguments_groups_and_globals_are_accepted() {
+    let config = config_with(
+        "todo",
+        json!({
+            "args": [{ "name": "quiet_mode", "conflicts_with": ["verbose"] }],
+            "commands": [{
+                "name": "add",
+                "args": [
+                    { "name": "json", "conflicts_with": ["yaml"] },
+                    { "name": "yaml" },
+                    { "name": "out", "requires": ["format"], "required_unless_present": ["json"] }
+                ],
+                "groups": [{ "name": "format", "args": ["json", "yaml"] }]
+            }]
+        }),
+    );
+    assert_eq!(validation_messages(&config), Vec::<String>::new());
+}
+
+#[test]
+fn group_names_must_be_snake_case() {
+    assert_eq!(
+        group_errors(json!([{ "name": "Format", "args": ["json"] }])),
+        ["Group name 'Format' in command 'add' must be a snake_case identifier"]
+    );
+}
+
+#[test]
+fn group_names_must_be_unique_among_arguments_and_groups() {
+    assert_eq!(
+        group_errors(json!([
+            { "name": "json", "args": ["yaml"] },
+            { "name": "format", "args": ["json"] },
+            { "name": "format", "args": ["yaml"] }
+        ])),
+        [
+            "Duplicate group 'json' in command 'add'",
+            "Duplicate group 'format' in command 'add'"
+        ]
+    );
+}
+
+#[test]
+fn group_names_must_not_shadow_global_options() {
+    let config = config_with(
+        "todo",
+        json!({ "args": [{ "name": "json" }], "groups": [{ "name": "verbose", "args": ["json"] }] }),
+    );
+    assert_eq!(
+        validation_messages(&config),
+        ["Duplicate group 'verbose' in the CLI"]
+    );
+}
+
+#[test]
+fn groups_need_arguments() {
+    assert_eq!(
+        group_errors(json!([{ "name": "format", "args": [] }])),
+        ["Group 'format' in command 'add' has no arguments"]
+    );
+}
+
+#[test]
+fn group_members_must_be_known_arguments() {
+    assert_eq!(
+        group_errors(json!([{ "name": "format", "args": ["json", "toml"] }])),
+        ["Group 'format' in command 'add' refers to unknown argument 'toml'"]
+    );
+}
+
+#[test]
+fn group_members_must_be_listed_once() {
+    assert_eq!(
+        group_errors(json!([{ "name": "format", "args": ["json", "yaml", "json"] }])),
+        ["Argument 'json' is listed twice in group 'format' in command 'add'"]
+    );
+}
+
+#[test]
+fn arguments_cannot_refer_to_themselves() {
+    assert_eq!(
+        arg_errors(json!([{ "name": "json", "requires": ["json"] }])),
+        ["Argument 'json' in command 'add' refers to itself"]
+    );
+}
+
+#[test]
+fn relations_must_name_known_arguments_or_groups() {
+    assert_eq!(
+        arg_errors(json!([
+            { "name": "json", "conflicts_with": ["yaml"], "required_unless_present": ["toml"] }
+        ])),
+        [
+            "Argument 'json' in command 'add' refers to unknown argument or group 'yaml'",
+            "Argument 'json' in command 'add' refers to unknown argument or group 'toml'"
+        ]
+    );
+}
+
+#[test]
+fn command_relations_cannot_name_global_options() {
+    assert_eq!(
+        arg_errors(json!([{ "name": "json", "conflicts_with": ["verbose"] }])),
+        ["Argument 'json' in command 'add' refers to unknown argument or group 'verbose'"]
+    );
+}
+
+#[test]
+fn required_arguments_cannot_be_required_unless_another_is_present() {
+    assert_eq!(
+        arg_errors(json!([
+            { "name": "json", "required": true, "required_unless_present": ["yaml"] },
+            { "name": "yaml" }
+        ])),
+        [
+            "Argument 'json' in command 'add' cannot be both required and required unless another is present"
+        ]
+    );
+}
+
+#[test]
+fn arguments_cannot_require_what_they_conflict_with() {
+    assert_eq!(
+        arg_errors(json!([
+            { "name": "json", "requires": ["yaml"], "conflicts_with": ["yaml"] },
+            { "name": "yaml" }
+        ])),
+        ["Argument 'json' in command 'add' both requires and conflicts with 'yaml'"]
+    );
+}
+
+#[test]
+fn arguments_cannot_require_what_conflicts_with_them() {
+    assert_eq!(
+        arg_errors(json!([
+            { "name": "json", "requires": ["yaml"] },
+            { "name": "yaml", "conflicts_with": ["json"] }
+        ])),
+        ["Argument 'json' in command 'add' requires 'yaml', which conflicts with it"]
+    );
+}
+
+#[test]
+fn required_arguments_cannot_conflict() {
+    assert_eq!(
+        arg_errors(json!([
+            { "name": "json", "required": true, "conflicts_with": ["yaml"] },
+            { "name": "yaml", "required": true }
+        ])),
+        ["Required arguments 'json' and 'yaml' in command 'add' conflict"]
+    );
+}
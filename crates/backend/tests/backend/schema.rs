@@ -0,0 +1,123 @@
+use cli_codegen_backend::migrate::ConfigMigrator;
+use cli_codegen_backend::models::config::CliConfig;
+use cli_codegen_backend::schema::{ConfigSchema, Diagnostic, Severity};
+use serde_json::{Value, json};
+use std::path::PathBuf;
+
+/// The default configuration with the top-level `overrides` merged in, as
+/// written.
+fn document(overrides: Value) -> String {
+    let mut document = serde_json::to_value(CliConfig::default()).unwrap();
+    for (key, value) in overrides.as_object().unwrap() {
+        document[key] = value.clone();
+    }
+    serde_json::to_string_pretty(&document).unwrap()
+}
+
+fn only(diagnostics: Vec<Diagnostic>) -> Diagnostic {
+    assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+    diagnostics.into_iter().next().unwrap()
+}
+
+#[test]
+fn the_default_config_is_valid() {
+    let source = serde_json::to_string_pretty(&CliConfig::default()).unwrap();
+    assert_eq!(ConfigSchema::validate(&source), []);
+}
+
+#[test]
+fn every_migration_fixture_is_valid() {
+    let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/migrations");
+    for entry in std::fs::read_dir(fixtures).unwrap() {
+        let path = entry.unwrap().path();
+        let source = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(ConfigSchema::validate(&source), [], "{}", path.display());
+    }
+}
+
+#[test]
+fn enum_variants_with_content_are_valid() {
+    let source = document(json!({
+        "license": { "Custom": "MPL-2.0" },
+        "sw_cli_dependency": { "Git": { "rev": null, "tag": "v0.2.0" } },
+        "args": [{ "name": "file", "short": "f", "kind": "Option" }]
+    }));
+    assert_eq!(ConfigSchema::validate(&source), []);
+}
+
+#[test]
+fn unknown_properties_are_warnings_as_batch_ignores_them() {
+    let source = document(json!({
+        "colour": true,
+        "commands": [{ "name": "add", "descriptoin": "Add an item" }]
+    }));
+    let diagnostics = ConfigSchema::validate(&source);
+    let found: Vec<(Severity, &str)> = diagnostics
+        .iter()
+        .map(|d| (d.severity, d.path.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (Severity::Warning, "/colour"),
+            (Severity::Warning, "/commands/0/descriptoin")
+        ]
+    );
+    assert!(
+        diagnostics[0]
+            .to_string()
+            .ends_with(": warning: /colour: unknown property \"colour\" is ignored"),
+        "{}",
+        diagnostics[0]
+    );
+    ConfigMigrator::from_json(&source).unwrap();
+}
+
+#[test]
+fn extra_keys_of_enum_variants_are_errors() {
+    let source = document(json!({ "license": { "Custom": "MPL-2.0", "Other": "" } }));
+    let diagnostic = only(ConfigSchema::validate(&source));
+    assert_eq!(diagnostic.severity, Severity::Error);
+    assert_eq!(diagnostic.path, "/license/Other");
+    assert_eq!(diagnostic.message, "unknown property \"Other\"");
+}
+
+#[test]
+fn length_errors_name_the_limit() {
+    let short = |short: &str| {
+        let source = document(json!({ "args": [{ "name": "file", "short": short }] }));
+        only(ConfigSchema::validate(&source)).message
+    };
+    assert_eq!(short("pp"), "\"pp\" is longer than 1 character");
+    assert_eq!(short(""), "\"\" is shorter than 1 character");
+}
+
+#[test]
+fn positions_count_escaped_characters_once() {
+    let line = r#"  "long_description": "say \"hi\" \\ \u00e9t\u00e9 \ud83d\ude00 é", "logging": "Trace","#;
+    let source = document(json!({})).replacen("{\n", &format!("{{\n{}\n", line), 1);
+    let diagnostic = only(ConfigSchema::validate(&source));
+    assert_eq!(diagnostic.path, "/logging");
+    assert_eq!(diagnostic.line, 2);
+    let column = line[..line.find("\"Trace\"").unwrap()].chars().count() + 1;
+    assert_eq!(diagnostic.column, column);
+}
+
+#[test]
+fn escaped_strings_are_decoded() {
+    let source = document(json!({})).replacen(
+        "{\n",
+        "{\n  \"logging\": \"Tr\\u0061cing\",\n  \"name\": \"\\u0074odo\",\n",
+        1,
+    );
+    // The duplicate keys are fine for serde, which keeps the last one
+    assert_eq!(ConfigSchema::validate(&source), []);
+
+    let source = document(json!({ "logging": "Tr\u{0061}ce\t" }));
+    let diagnostic = only(ConfigSchema::validate(&source));
+    assert!(
+        diagnostic.message.starts_with("\"Trace\\t\" is not one of"),
+        "{}",
+        diagnostic.message
+    );
+}
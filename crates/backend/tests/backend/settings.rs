@@ -0,0 +1,89 @@
+use crate::common::{config_with, rendered};
+use serde_json::json;
+
+#[test]
+fn argument_env_vars_are_not_read_as_settings() {
+    let config = config_with(
+        "todo-cli",
+        json!({
+            "layout": "Split",
+            "settings": true,
+            "args": [{ "name": "output", "env": true }],
+            "commands": [{
+                "name": "add",
+                "args": [
+                    { "name": "priority", "env": true },
+                    { "name": "tag" }
+                ]
+            }]
+        }),
+    );
+    let settings = rendered(&config, "src/settings.rs");
+    assert!(settings.contains(
+        "pub const ARG_ENV_VARS: &[&str] = &[\"TODO_CLI_OUTPUT\", \"TODO_CLI_ADD_PRIORITY\"];\n"
+    ));
+    assert!(settings.contains("!ARG_ENV_VARS.contains(&var.as_str())"));
+}
+
+#[test]
+fn long_lists_of_argument_env_vars_are_one_per_line() {
+    let args: Vec<_> = ["first", "second", "third", "fourth", "fifth"]
+        .iter()
+        .map(|name| json!({ "name": format!("{}_value", name), "env": true }))
+        .collect();
+    let config = config_with(
+        "todo-cli",
+        json!({ "layout": "Split", "settings": true, "args": args }),
+    );
+    let settings = rendered(&config, "src/settings.rs");
+    assert!(settings.contains(
+        "pub const ARG_ENV_VARS: &[&str] = &[\n    \"TODO_CLI_FIRST_VALUE\",\n    \"TODO_CLI_SECOND_VALUE\","
+    ));
+    assert!(settings.contains("    \"TODO_CLI_FIFTH_VALUE\",\n];\n"));
+}
+
+#[test]
+fn settings_without_argument_env_vars_skip_none() {
+    let config = config_with("todo-cli", json!({ "layout": "Split", "settings": true }));
+    let settings = rendered(&config, "src/settings.rs");
+    assert!(settings.contains("pub const ARG_ENV_VARS: &[&str] = &[];\n"));
+}
+
+#[test]
+fn main_loads_the_settings_once_and_maps_their_errors_to_exit_codes() {
+    let config = config_with(
+        "todo-cli",
+        json!({
+            "layout": "Split",
+            "settings": true,
+            "error_handling": "Thiserror",
+            "sysexits": true
+        }),
+    );
+    let main = rendered(&config, "src/main.rs");
+    assert_eq!(main.matches("Settings::load(").count(), 1, "{}", main);
+    assert!(main.contains("            return error::exit_code(&e.into());\n"));
+    assert!(main.contains("        print!(\"{}\", settings);\n        return ExitCode::SUCCESS;"));
+    assert!(main.contains("match run(&cli, &settings) {"));
+    assert!(main.contains("fn run(cli: &Cli, settings: &Settings) -> Result<()> {"));
+
+    let error = rendered(&config, "src/error.rs");
+    assert!(error.contains("Error::Settings(_) => Exit::Config,"));
+}
+
+#[test]
+fn anyhow_maps_settings_errors_to_ex_config() {
+    let config = config_with(
+        "todo-cli",
+        json!({
+            "layout": "Split",
+            "settings": true,
+            "error_handling": "Anyhow",
+            "sysexits": true
+        }),
+    );
+    let error = rendered(&config, "src/error.rs");
+    assert!(error.contains(
+        "        if cause.is::<crate::settings::SettingsError>() {\n            return Exit::Config.into();\n        }"
+    ));
+}
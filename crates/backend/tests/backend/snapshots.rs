@@ -0,0 +1,89 @@
+use crate::common::{TempDir, config_with, rendered};
+use cli_codegen_backend::codegen::generators::CodeGenerator;
+use cli_codegen_backend::models::manifest::{MANIFEST_FILE, ProjectManifest};
+use serde_json::json;
+
+/// A dependency-free project named `snap` with an `add` command and the
+/// built-in `completions` and `config` commands. Its `tests/help.rs` is
+/// the real template, run against `main_rs`, next to snapshots of an older
+/// rendering.
+fn project(name: &str, main_rs: &str) -> TempDir {
+    let dir = TempDir::new(name);
+    let config = config_with(
+        "snap",
+        json!({
+            "snapshot_tests": true,
+            "completions": true,
+            "settings": true,
+            "commands": [{ "name": "add" }]
+        }),
+    );
+    dir.write("tests/help.rs", &rendered(&config, "tests/help.rs"));
+    let manifest = ProjectManifest {
+        cli_gen_version: String::new(),
+        generated_at: String::new(),
+        template_version: String::new(),
+        config,
+        files: Vec::new(),
+    };
+    dir.write(MANIFEST_FILE, &toml::to_string(&manifest).unwrap());
+    dir.write(
+        "Cargo.toml",
+        "[package]\nname = \"snap\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[workspace]\n",
+    );
+    dir.write("src/main.rs", main_rs);
+    dir.write("tests/snapshots/help.txt", "old\n");
+    dir.write("tests/snapshots/removed-help.txt", "old\n");
+    dir.write("tests/snapshots/README.md", "kept\n");
+    dir
+}
+
+fn snapshot(dir: &TempDir, file: &str) -> Option<String> {
+    dir.read(&format!("tests/snapshots/{}", file))
+}
+
+#[test]
+fn stale_snapshots_are_removed_after_a_successful_run() {
+    let dir = project(
+        "snapshots-ok",
+        "fn main() {\n    println!(\"help of {:?}\", std::env::args().skip(1).collect::<Vec<_>>());\n}\n",
+    );
+
+    let snapshots = CodeGenerator::refresh_snapshots(dir.as_str()).unwrap();
+    let names: Vec<&str> = snapshots
+        .iter()
+        .map(|path| path.rsplit('/').next().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "README.md",
+            "add-help.txt",
+            "completions-help.txt",
+            "config-help.txt",
+            "help.txt"
+        ]
+    );
+    assert_eq!(
+        snapshot(&dir, "config-help.txt").as_deref(),
+        Some("help of [\"config\", \"--help\"]\n")
+    );
+    assert_eq!(snapshot(&dir, "removed-help.txt"), None);
+}
+
+#[test]
+fn snapshots_are_kept_when_the_run_fails() {
+    let dir = project(
+        "snapshots-failed",
+        "fn main() {\n    std::process::exit(1);\n}\n",
+    );
+
+    let error = CodeGenerator::refresh_snapshots(dir.as_str()).unwrap_err();
+    assert!(
+        error.to_string().starts_with("Snapshot tests failed"),
+        "{}",
+        error
+    );
+    assert_eq!(snapshot(&dir, "help.txt").as_deref(), Some("old\n"));
+    assert_eq!(snapshot(&dir, "removed-help.txt").as_deref(), Some("old\n"));
+}
@@ -0,0 +1,148 @@
+use crate::common::{TempDir, config_with};
+use cli_codegen_backend::codegen::generators::CodeGenerator;
+use cli_codegen_backend::codegen::update::{UpdateReport, UpdateStatus};
+use cli_codegen_backend::models::config::CliConfig;
+use cli_codegen_backend::models::manifest::BASE_DIR;
+use serde_json::json;
+
+fn config(description: &str, ci: serde_json::Value) -> CliConfig {
+    config_with(
+        "todo",
+        json!({ "layout": "Split", "short_description": description, "ci": ci }),
+    )
+}
+
+/// A project generated with a Makefile and the description `Track old
+/// items`.
+fn generated(name: &str) -> TempDir {
+    let dir = TempDir::new(name);
+    CodeGenerator::generate_files(
+        &config("Track old items", json!(["Makefile"])),
+        dir.as_str(),
+    )
+    .unwrap();
+    dir
+}
+
+/// Update to the description `Track new items`, without the Makefile.
+fn update(dir: &TempDir, dry_run: bool) -> UpdateReport {
+    let config = config("Track new items", json!([]));
+    CodeGenerator::update_project(dir.as_str(), Some(config), dry_run).unwrap()
+}
+
+fn status(report: &UpdateReport, path: &str) -> UpdateStatus {
+    report
+        .files
+        .iter()
+        .find(|file| file.path == path)
+        .unwrap_or_else(|| panic!("{} is not in the report", path))
+        .status
+}
+
+/// Edit the files of a fresh project so each ends in another status.
+fn edit_for_every_status(dir: &TempDir) {
+    // Edited where the templates do not change
+    let copyright = dir.read("COPYRIGHT").unwrap();
+    dir.write("COPYRIGHT", &format!("{}Local notice\n", copyright));
+    // Edited away from the description, which the templates change
+    let readme = dir.read("README.md").unwrap();
+    dir.write("README.md", &format!("{}\nLocal section\n", readme));
+    // Edited on the line the templates change
+    let manifest = dir.read("Cargo.toml").unwrap();
+    dir.write(
+        "Cargo.toml",
+        &manifest.replace("Track old items", "Track my items"),
+    );
+    // Neither on disk nor recorded
+    dir.remove("build.rs");
+    dir.remove(&format!("{}/build.rs", BASE_DIR));
+    dir.remove("tests/cli.rs");
+}
+
+#[test]
+fn every_file_gets_the_status_of_its_changes() {
+    let dir = generated("update-statuses");
+    edit_for_every_status(&dir);
+    let report = update(&dir, false);
+
+    assert_eq!(status(&report, ".gitignore"), UpdateStatus::Unchanged);
+    assert_eq!(status(&report, "src/short-help.txt"), UpdateStatus::Updated);
+    assert_eq!(status(&report, "COPYRIGHT"), UpdateStatus::Kept);
+    assert_eq!(status(&report, "README.md"), UpdateStatus::Merged);
+    assert_eq!(status(&report, "Cargo.toml"), UpdateStatus::Conflict);
+    assert_eq!(status(&report, "build.rs"), UpdateStatus::Created);
+    assert_eq!(
+        status(&report, "tests/cli.rs"),
+        UpdateStatus::DeletedLocally
+    );
+    assert_eq!(status(&report, "Makefile"), UpdateStatus::Orphaned);
+    assert_eq!(report.conflicts(), 1);
+}
+
+#[test]
+fn updates_write_merged_files_and_new_base_copies() {
+    let dir = generated("update-writes");
+    edit_for_every_status(&dir);
+    update(&dir, false);
+
+    assert!(
+        dir.read("src/short-help.txt")
+            .unwrap()
+            .starts_with("Track new items\n")
+    );
+    assert!(dir.read("COPYRIGHT").unwrap().ends_with("Local notice\n"));
+    let readme = dir.read("README.md").unwrap();
+    assert!(readme.contains("Track new items") && readme.ends_with("Local section\n"));
+    let manifest = dir.read("Cargo.toml").unwrap();
+    assert!(manifest.contains("<<<<<<<") && manifest.contains("Track my items"));
+    assert!(dir.read("build.rs").is_some());
+    assert_eq!(dir.read("tests/cli.rs"), None);
+    assert!(dir.read("Makefile").is_some());
+
+    // The rendering just merged is the ancestor of the next update
+    let base = |path: &str| dir.read(&format!("{}/{}", BASE_DIR, path));
+    assert!(
+        base("src/short-help.txt")
+            .unwrap()
+            .starts_with("Track new items\n")
+    );
+    assert_eq!(base("Makefile"), None);
+    assert_eq!(
+        status(&update(&dir, true), "src/short-help.txt"),
+        UpdateStatus::Unchanged
+    );
+}
+
+#[test]
+fn dry_runs_write_nothing() {
+    let dir = generated("update-dry-run");
+    edit_for_every_status(&dir);
+    let report = update(&dir, true);
+
+    assert_eq!(status(&report, "src/short-help.txt"), UpdateStatus::Updated);
+    assert!(
+        dir.read("src/short-help.txt")
+            .unwrap()
+            .starts_with("Track old items\n")
+    );
+    assert_eq!(dir.read("build.rs"), None);
+    assert!(dir.read(&format!("{}/Makefile", BASE_DIR)).is_some());
+}
+
+#[test]
+fn without_base_copies_every_change_conflicts() {
+    let dir = generated("update-no-base");
+    std::fs::remove_dir_all(dir.path(BASE_DIR)).unwrap();
+    let report = update(&dir, false);
+
+    for path in ["src/short-help.txt", "README.md", "Cargo.toml"] {
+        assert_eq!(status(&report, path), UpdateStatus::Conflict, "{}", path);
+    }
+    assert_eq!(status(&report, ".gitignore"), UpdateStatus::Unchanged);
+    assert!(report.files.iter().all(|file| matches!(
+        file.status,
+        UpdateStatus::Conflict | UpdateStatus::Unchanged
+    )));
+    // Without a record of it, the Makefile is not known to be orphaned
+    assert!(report.files.iter().all(|file| file.path != "Makefile"));
+}
@@ -0,0 +1,107 @@
+use crate::common::{config, config_with, validation_messages};
+use cli_codegen_backend::schema::ConfigSchema;
+use serde_json::json;
+
+/// A valid document with the top-level `overrides` merged in as written.
+fn document(overrides: serde_json::Value) -> String {
+    let mut document = serde_json::to_value(config("todo")).unwrap();
+    for (key, value) in overrides.as_object().unwrap() {
+        document[key] = value.clone();
+    }
+    serde_json::to_string_pretty(&document).unwrap()
+}
+
+fn messages(source: &str) -> Vec<String> {
+    ConfigSchema::validate(source)
+        .into_iter()
+        .map(|diagnostic| diagnostic.message)
+        .collect()
+}
+
+#[test]
+fn every_semantic_error_is_reported() {
+    let source = document(json!({
+        "commands": [
+            { "name": "Zed" },
+            { "name": "add", "args": [{ "name": "Bad_Name" }] },
+            { "name": "add" }
+        ]
+    }));
+    assert_eq!(
+        messages(&source),
+        [
+            "Command name 'Zed' must be lowercase letters, digits and dashes",
+            "Argument name 'Bad_Name' in command 'add' must be a snake_case identifier",
+            "Duplicate command 'add'",
+        ]
+    );
+}
+
+#[test]
+fn each_check_reports_all_of_its_errors() {
+    let config = config_with(
+        "todo",
+        json!({
+            "error_handling": "Thiserror",
+            "global_options": ["Verbose", "Verbose", "Verbosity"],
+            "exit_codes": [
+                { "name": "not-found", "code": 3 },
+                { "name": "gone", "code": 3 },
+                { "name": "not_found!", "code": 4 }
+            ]
+        }),
+    );
+    assert_eq!(
+        validation_messages(&config),
+        [
+            "Duplicate global option Verbose",
+            "The Verbose and Verbosity global options both use -v/--verbose; pick one",
+            "Exit code 3 of 'gone' is already used",
+            "Invalid exit code name 'not_found!'",
+        ]
+    );
+}
+
+#[test]
+fn semantic_errors_are_reported_next_to_schema_errors() {
+    let source = document(json!({
+        "commands": [{ "name": "Zed", "descriptoin": "typo" }]
+    }));
+    let diagnostics = ConfigSchema::validate(&source);
+    let paths: Vec<&str> = diagnostics.iter().map(|d| d.path.as_str()).collect();
+    assert_eq!(paths, ["/commands/0/descriptoin", "/commands/0/name"]);
+}
+
+#[test]
+fn semantic_errors_point_at_the_offending_value() {
+    let source = document(json!({
+        "commands": [
+            { "name": "add", "args": [{ "name": "text" }] },
+            { "name": "remove", "args": [{ "name": "text" }, { "name": "text" }] }
+        ]
+    }));
+    let diagnostics = ConfigSchema::validate(&source);
+    assert_eq!(diagnostics[0].path, "/commands/1/args/1/name");
+    assert_eq!(
+        diagnostics[0].message,
+        "Duplicate argument 'text' in command 'remove'"
+    );
+    // The second `"text"` of the document
+    let offset = source.rfind("\"text\"").unwrap();
+    let line = source[..offset].lines().count();
+    assert_eq!(diagnostics[0].line, line);
+}
+
+#[test]
+fn schema_errors_do_not_hide_semantic_errors() {
+    let source = document(json!({
+        "commands": [
+            { "name": "add", "args": [{ "name": "text", "kind": "Nope" }] },
+            { "name": "add" }
+        ]
+    }));
+    let diagnostics = ConfigSchema::validate(&source);
+    let paths: Vec<&str> = diagnostics.iter().map(|d| d.path.as_str()).collect();
+    assert_eq!(paths, ["/commands/0/args/0/kind", "/commands/1/name"]);
+    assert_eq!(diagnostics[1].message, "Duplicate command 'add'");
+}
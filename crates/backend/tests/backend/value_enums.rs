@@ -0,0 +1,63 @@
+use crate::common::{config_with, rendered, validation_error};
+use serde_json::json;
+
+#[test]
+fn values_render_a_value_enum() {
+    let config = config_with(
+        "fmt",
+        json!({
+            "layout": "Split",
+            "commands": [{
+                "name": "list",
+                "args": [{
+                    "name": "format",
+                    "default_value": "json",
+                    "values": [
+                        { "name": "json", "description": "Machine readable" },
+                        { "name": "plain-text" },
+                        { "name": "v-2" }
+                    ]
+                }]
+            }]
+        }),
+    );
+    let cli = rendered(&config, "src/cli.rs");
+    assert!(
+        cli.contains("#[arg(long, default_value = \"json\")]\n        format: ListFormat,"),
+        "{}",
+        cli
+    );
+    assert!(
+        cli.contains(
+            "/// Values of the `format` argument of the `list` command.\n\
+         #[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]\n\
+         pub enum ListFormat {\n    \
+         /// Machine readable\n    Json,\n    \
+         PlainText,\n    \
+         #[value(name = \"v-2\")]\n    V2,\n}"
+        ),
+        "{}",
+        cli
+    );
+}
+
+#[test]
+fn value_enums_cannot_reuse_generated_type_names() {
+    let values = json!([{ "name": "a" }, { "name": "b" }]);
+    for overrides in [
+        json!({
+            "global_options": ["Output"],
+            "commands": [{ "name": "output", "args": [{ "name": "format", "values": values }] }]
+        }),
+        json!({ "completions": true, "args": [{ "name": "completions_command", "values": values }] }),
+        json!({ "architecture": "Dispatch", "args": [{ "name": "cli_dispatcher", "values": values }] }),
+        json!({ "commands": [{ "name": "command", "args": [{ "name": "handler", "values": values }] }] }),
+    ] {
+        let error = validation_error(&config_with("types", overrides));
+        assert!(
+            error.contains("would generate a second type named"),
+            "{}",
+            error
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use crate::common::{config_with, rendered};
+use cli_codegen_backend::codegen::generators::CodeGenerator;
+use cli_codegen_backend::models::config::CliConfig;
+use serde_json::json;
+
+fn paths(config: &CliConfig) -> Vec<String> {
+    CodeGenerator::render_files(config)
+        .unwrap()
+        .into_iter()
+        .map(|file| file.path)
+        .collect()
+}
+
+#[test]
+fn xtask_replaces_the_scripts() {
+    let config = config_with("todo", json!({ "layout": "Split", "xtask": true }));
+    let paths = paths(&config);
+    for path in [
+        "xtask/Cargo.toml",
+        "xtask/src/main.rs",
+        ".cargo/config.toml",
+    ] {
+        assert!(paths.contains(&path.to_string()), "{}", path);
+    }
+    assert!(!paths.iter().any(|path| path.starts_with("scripts/")));
+    assert_eq!(
+        rendered(&config, ".cargo/config.toml"),
+        "[alias]\nxtask = \"run --package xtask --\"\n"
+    );
+    let manifest = rendered(&config, "Cargo.toml");
+    assert!(
+        manifest.contains("\n[workspace]\nmembers = [\"xtask\"]"),
+        "{}",
+        manifest
+    );
+}
+
+#[test]
+fn scripts_are_kept_without_xtask() {
+    let paths = paths(&config_with("todo", json!({ "layout": "Split" })));
+    assert!(paths.contains(&"scripts/setup.sh".to_string()));
+    assert!(paths.contains(&"scripts/build.sh".to_string()));
+    assert!(!paths.iter().any(|path| path.starts_with("xtask/")));
+}
+
+#[test]
+fn workspace_adds_xtask_as_a_member() {
+    let config = config_with("todo", json!({ "layout": "Workspace", "xtask": true }));
+    let manifest = rendered(&config, "Cargo.toml");
+    assert!(manifest.contains(
+        "members = [\"crates/todo\", \"crates/todo-core\", \"xtask\"]\n\
+         default-members = [\"crates/todo\", \"crates/todo-core\"]"
+    ));
+    assert!(!manifest.contains("members = [\"xtask\"]"), "{}", manifest);
+}
+
+#[test]
+fn dist_collects_completions_and_man_pages() {
+    let config = config_with(
+        "todo",
+        json!({ "layout": "Workspace", "xtask": true, "completions": true, "man_pages": true }),
+    );
+    let xtask = rendered(&config, "xtask/src/main.rs");
+    assert!(xtask.contains("const BIN: &str = \"todo\";\n"));
+    assert!(xtask.contains("Collect the release binary, completions and man pages in target/dist"));
+    assert!(xtask.contains(".args([\"completions\", shell])"));
+    assert!(xtask.contains("            \"todo-core\",\n            \"--example\",\n"));
+}
+
+#[test]
+fn setup_follows_the_dependency_mode() {
+    let submodule = config_with(
+        "todo",
+        json!({ "xtask": true, "sw_cli_dependency": "Submodule" }),
+    );
+    let xtask = rendered(&submodule, "xtask/src/main.rs");
+    assert!(
+        xtask.contains("run(\"git\", &[\"submodule\", \"update\", \"--init\", \"--recursive\"])?;")
+    );
+    assert!(xtask.contains("\"--remote\",\n            \"--merge\","));
+
+    let path = config_with(
+        "todo",
+        json!({ "xtask": true, "sw_cli_dependency": { "Path": { "path": "../sw-cli" } } }),
+    );
+    let xtask = rendered(&path, "xtask/src/main.rs");
+    assert!(xtask.contains("return Err(\"sw-cli checkout not found at ../sw-cli\".into());"));
+    assert!(!xtask.contains("submodule"));
+}
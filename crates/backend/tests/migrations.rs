@@ -0,0 +1,145 @@
+use cli_codegen_backend::codegen::generators::CodeGenerator;
+use cli_codegen_backend::migrate::ConfigMigrator;
+use cli_codegen_backend::models::config::{
+    CONFIG_SCHEMA_VERSION, GlobalOption, Layout, SwCliDependency,
+};
+use cli_codegen_backend::models::manifest::ProjectManifest;
+use serde_json::{Value, json};
+use std::path::PathBuf;
+
+/// Configuration documents as written by each historical format version.
+const FIXTURES: [&str; 4] = [
+    "v0-baseline.json",
+    "v0-commands.json",
+    "v0-layouts.json",
+    "v1-current.json",
+];
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/migrations")
+        .join(name)
+}
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(fixture_path(name)).unwrap()
+}
+
+#[test]
+fn every_fixture_loads_at_the_current_version() {
+    for name in FIXTURES {
+        let config =
+            ConfigMigrator::from_json(&fixture(name)).unwrap_or_else(|e| panic!("{}: {}", name, e));
+        assert_eq!(config.schema_version, CONFIG_SCHEMA_VERSION, "{}", name);
+        CodeGenerator::validate_config(&config).unwrap_or_else(|e| panic!("{}: {}", name, e));
+    }
+}
+
+#[test]
+fn fixtures_are_named_after_their_version() {
+    for name in FIXTURES {
+        let document: Value = serde_json::from_str(&fixture(name)).unwrap();
+        let version = ConfigMigrator::document_version(&document).unwrap();
+        assert!(name.starts_with(&format!("v{}-", version)), "{}", name);
+    }
+}
+
+#[test]
+fn version_0_documents_keep_their_meaning() {
+    let baseline = ConfigMigrator::from_json(&fixture("v0-baseline.json")).unwrap();
+    assert_eq!(baseline.name, "greet");
+    assert_eq!(baseline.sw_cli_dependency, SwCliDependency::Submodule);
+    assert_eq!(
+        baseline.global_options,
+        [GlobalOption::Verbose, GlobalOption::DryRun]
+    );
+    assert!(baseline.commands.is_empty());
+
+    let commands = ConfigMigrator::from_json(&fixture("v0-commands.json")).unwrap();
+    assert_eq!(
+        commands.sw_cli_dependency,
+        SwCliDependency::Git {
+            rev: None,
+            tag: Some("v0.2.0".to_string())
+        }
+    );
+    assert_eq!(commands.args[0].short, Some('f'));
+    assert_eq!(commands.commands[0].args[1].value_type, "u8");
+
+    let layouts = ConfigMigrator::from_json(&fixture("v0-layouts.json")).unwrap();
+    assert_eq!(layouts.layout, Layout::Workspace);
+    assert_eq!(layouts.exit_codes[0].code, 4);
+}
+
+#[test]
+fn current_documents_are_not_changed() {
+    let document: Value = serde_json::from_str(&fixture("v1-current.json")).unwrap();
+    assert_eq!(ConfigMigrator::migrate(document.clone()).unwrap(), document);
+}
+
+#[test]
+fn migration_is_idempotent() {
+    for name in FIXTURES {
+        let document: Value = serde_json::from_str(&fixture(name)).unwrap();
+        let once = ConfigMigrator::migrate(document).unwrap();
+        assert_eq!(once["schema_version"], json!(CONFIG_SCHEMA_VERSION));
+        assert_eq!(
+            ConfigMigrator::migrate(once.clone()).unwrap(),
+            once,
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn newer_versions_are_rejected() {
+    let mut document: Value = serde_json::from_str(&fixture("v1-current.json")).unwrap();
+    document["schema_version"] = json!(CONFIG_SCHEMA_VERSION + 1);
+    let error = ConfigMigrator::migrate(document).unwrap_err();
+    assert!(
+        error.contains("newer than the latest supported"),
+        "{}",
+        error
+    );
+
+    let error = ConfigMigrator::migrate(json!({ "schema_version": "1" })).unwrap_err();
+    assert!(error.contains("non-negative integer"), "{}", error);
+}
+
+#[test]
+fn migrate_file_rewrites_old_files_in_place() {
+    let dir = std::env::temp_dir().join(format!("cli-gen-migrate-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("config.json");
+    std::fs::copy(fixture_path("v0-commands.json"), &path).unwrap();
+    let path = path.to_str().unwrap();
+
+    assert_eq!(ConfigMigrator::migrate_file(path).unwrap(), 0);
+    let migrated = std::fs::read_to_string(path).unwrap();
+    let document: Value = serde_json::from_str(&migrated).unwrap();
+    assert_eq!(document["schema_version"], json!(CONFIG_SCHEMA_VERSION));
+    assert_eq!(document["commands"][0]["args"][0]["name"], "title");
+
+    // A second run finds the file current and leaves it alone
+    assert_eq!(
+        ConfigMigrator::migrate_file(path).unwrap(),
+        CONFIG_SCHEMA_VERSION
+    );
+    assert_eq!(std::fs::read_to_string(path).unwrap(), migrated);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn manifests_of_old_projects_are_migrated() {
+    let config: Value = serde_json::from_str(&fixture("v0-baseline.json")).unwrap();
+    let manifest = toml::to_string(&json!({
+        "template_version": "0123abcd",
+        "config": config,
+    }))
+    .unwrap();
+
+    let manifest: ProjectManifest = toml::from_str(&manifest).unwrap();
+    assert_eq!(manifest.config.schema_version, CONFIG_SCHEMA_VERSION);
+    assert_eq!(manifest.config.name, "greet");
+}